@@ -0,0 +1,195 @@
+use serde::{Deserialize, Serialize};
+
+use crate::statistics::{CHARS_PER_WORD, MS_PER_MINUTE};
+
+/// every step makes the words this much faster.
+const SPEED_PER_STEP: f64 = 1.1;
+/// one more or less word on screen every this many steps.
+const STEPS_PER_WORD: i32 = 4;
+/// longest word on the easiest steps, shrinking with every step below 0.
+const EASY_MAX_LENGTH: i32 = 10;
+const SHORTEST_MAX_LENGTH: i32 = 3;
+/// the difficulty stays within -MAX_STEP..=MAX_STEP.
+const MAX_STEP: i32 = 10;
+
+/// Settings of the adaptive difficulty, which keeps the miss rate of the player within a band.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Adaptive {
+  // the difficulty is adjusted every this many ms
+  pub interval: u128,
+  // below this ratio of missed words, the game gets harder
+  pub min_miss_rate: f64,
+  // above this ratio of missed words, the game gets easier
+  pub max_miss_rate: f64,
+}
+
+impl Default for Adaptive {
+  fn default() -> Self {
+    Adaptive {
+      interval: 10_000,
+      min_miss_rate: 0.05,
+      max_miss_rate: 0.2,
+    }
+  }
+}
+
+impl Adaptive {
+  pub fn validate(&self) -> Result<(), String> {
+    if self.interval == 0 {
+      return Err(String::from("The adaptive difficulty needs an interval longer than 0ms!"));
+    }
+    if !(0.0..=self.max_miss_rate).contains(&self.min_miss_rate) || self.max_miss_rate > 1.0 {
+      return Err(String::from("The miss rates of the adaptive difficulty need to be 0 <= min <= max <= 1!"));
+    }
+    Ok(())
+  }
+}
+
+/// A change of the difficulty made during a game.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Decision {
+  // time of the change in ms since the game started
+  pub time: u128,
+  // the difficulty after the change
+  pub step: i32,
+  // WPM of the player within the last interval
+  pub wpm: f64,
+  // WPM needed to type all words before they hit the ground
+  pub demand_wpm: f64,
+  // ratio of missed words within the last interval
+  pub miss_rate: f64,
+}
+
+/// What the player achieved so far, as seen by [Difficulty::evaluate].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Progress {
+  pub time: u128,
+  pub words: u128,
+  pub fails: u16,
+  // correctly typed characters
+  pub accepted: u128,
+}
+
+/// The current adjustment of the difficulty, 0 plays the game as the progression defines it.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Difficulty {
+  pub step: i32,
+  pub decisions: Vec<Decision>,
+  // progress at the last evaluation
+  last: Progress,
+}
+
+impl Difficulty {
+  /// factor on the fall velocity of the words.
+  pub fn speed(&self) -> f64 {
+    SPEED_PER_STEP.powi(self.step)
+  }
+
+  /// the number of words on screen, based on the number of the level.
+  pub fn words(&self, words: usize) -> usize {
+    (words as i32 + self.step / STEPS_PER_WORD).max(1) as usize
+  }
+
+  /// whether a word may be spawned: easier steps prefer short words, harder ones long words.
+  pub fn allows(&self, word: &str) -> bool {
+    let length = word.chars().count() as i32;
+    match self.step {
+      step if step < 0 => length <= (EASY_MAX_LENGTH + step).max(SHORTEST_MAX_LENGTH),
+      step => length >= step / 2,
+    }
+  }
+
+  /// Adjusts the difficulty once an interval has passed since the last evaluation.
+  /// `demand_wpm` is the speed needed to type the words currently on screen in time.
+  pub fn evaluate(&mut self, adaptive: &Adaptive, progress: Progress, demand_wpm: f64) {
+    if progress.time < self.last.time + adaptive.interval {
+      return;
+    }
+    let words = progress.words - self.last.words;
+    let misses = (progress.fails - self.last.fails) as u128;
+    let minutes = (progress.time - self.last.time) as f64 / MS_PER_MINUTE;
+    let wpm = (progress.accepted - self.last.accepted) as f64 / CHARS_PER_WORD / minutes;
+    self.last = progress;
+    if words + misses == 0 {
+      return;
+    }
+    let miss_rate = misses as f64 / (words + misses) as f64;
+    let step = if miss_rate > adaptive.max_miss_rate {
+      self.step - 1
+    } else if miss_rate < adaptive.min_miss_rate && wpm >= demand_wpm {
+      self.step + 1
+    } else {
+      self.step
+    }.clamp(-MAX_STEP, MAX_STEP);
+    if step != self.step {
+      self.step = step;
+      self.decisions.push(Decision { time: progress.time, step, wpm, demand_wpm, miss_rate });
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::adaptive::{Adaptive, Difficulty, Progress};
+
+  fn progress(time: u128, words: u128, fails: u16) -> Progress {
+    Progress { time, words, fails, accepted: words * 5 }
+  }
+
+  #[test]
+  fn waits_for_the_interval() {
+    let mut difficulty = Difficulty::default();
+    difficulty.evaluate(&Adaptive::default(), progress(9_999, 10, 0), 1.0);
+    assert_eq!(difficulty.step, 0);
+    difficulty.evaluate(&Adaptive::default(), progress(10_000, 10, 0), 1.0);
+    assert_eq!(difficulty.step, 1);
+    assert_eq!(difficulty.decisions.len(), 1);
+    assert!((difficulty.decisions[0].wpm - 60.0).abs() < 1e-9);
+  }
+
+  #[test]
+  fn misses_make_it_easier() {
+    let mut difficulty = Difficulty::default();
+    difficulty.evaluate(&Adaptive::default(), progress(10_000, 5, 5), 1.0);
+    assert_eq!(difficulty.step, -1);
+    assert_eq!(difficulty.decisions[0].miss_rate, 0.5);
+    // only the last interval counts
+    difficulty.evaluate(&Adaptive::default(), progress(20_000, 25, 5), 1.0);
+    assert_eq!(difficulty.step, 0);
+  }
+
+  #[test]
+  fn stays_within_the_band() {
+    let mut difficulty = Difficulty::default();
+    // 1 of 10 words missed
+    difficulty.evaluate(&Adaptive::default(), progress(10_000, 9, 1), 1.0);
+    // no misses, but too slow for the words on screen
+    difficulty.evaluate(&Adaptive::default(), progress(20_000, 10, 1), 100.0);
+    // nothing typed at all
+    difficulty.evaluate(&Adaptive::default(), progress(30_000, 10, 1), 1.0);
+    assert_eq!(difficulty.step, 0);
+    assert!(difficulty.decisions.is_empty());
+  }
+
+  #[test]
+  fn adjustments() {
+    let difficulty = Difficulty { step: -8, ..Difficulty::default() };
+    assert!(difficulty.speed() < 1.0);
+    assert_eq!(difficulty.words(1), 1);
+    assert!(difficulty.allows("abc"));
+    assert!(!difficulty.allows("abcd"));
+    let difficulty = Difficulty { step: 8, ..Difficulty::default() };
+    assert!(difficulty.speed() > 2.0);
+    assert_eq!(difficulty.words(1), 3);
+    assert!(!difficulty.allows("abc"));
+    assert!(difficulty.allows("abcd"));
+  }
+
+  #[test]
+  fn invalid() {
+    assert!(Adaptive { interval: 0, ..Adaptive::default() }.validate().is_err());
+    assert!(Adaptive { min_miss_rate: 0.5, max_miss_rate: 0.1, ..Adaptive::default() }.validate().is_err());
+    assert!(Adaptive::default().validate().is_ok());
+  }
+}
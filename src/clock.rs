@@ -0,0 +1,61 @@
+use std::pin::Pin;
+use std::time::{Duration, Instant};
+
+use async_std::stream::interval;
+use futures::stream::{self, Stream, StreamExt};
+
+/// Drives the game loop. A clock delivers a stream of timestamps,
+/// each one being the milliseconds elapsed since the stream was created.
+pub trait Clock {
+  fn ticks(&self) -> Pin<Box<dyn Stream<Item=u128>>>;
+}
+
+/// Ticks in real time, using the wall clock for the timestamps.
+pub struct SystemClock {
+  period: Duration,
+}
+
+impl SystemClock {
+  pub fn new() -> Self {
+    // 60 FPS ~ 16.666_7ms => 16ms
+    SystemClock::new_with_period(Duration::from_millis(16))
+  }
+
+  pub fn new_with_period(period: Duration) -> Self {
+    SystemClock { period }
+  }
+}
+
+impl Default for SystemClock {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl Clock for SystemClock {
+  fn ticks(&self) -> Pin<Box<dyn Stream<Item=u128>>> {
+    let time = Instant::now();
+    interval(self.period)
+        .map(move |_| time.elapsed().as_millis())
+        .boxed()
+  }
+}
+
+/// Ticks as fast as it is polled, advancing the time by a fixed step on every tick.
+/// The timestamps don't depend on the wall clock, which makes games reproducible.
+pub struct StepClock {
+  step: u128,
+}
+
+impl StepClock {
+  pub fn new(step: u128) -> Self {
+    StepClock { step }
+  }
+}
+
+impl Clock for StepClock {
+  fn ticks(&self) -> Pin<Box<dyn Stream<Item=u128>>> {
+    let step = self.step;
+    stream::iter(1..).map(move |tick: u128| tick * step).boxed()
+  }
+}
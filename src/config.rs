@@ -0,0 +1,187 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::adaptive::Adaptive;
+use crate::matching::Matching;
+use crate::progression::Level;
+use crate::selection::Selection;
+use crate::spawning::Spawning;
+use crate::typeattack::Mode;
+
+/// Preferences of the user, stored as TOML. Everything not set in the file keeps its default,
+/// command line options take precedence over the file.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+  pub lives: Option<u16>,
+  pub speed: Option<f64>,
+  // chance of a new word being a power-up
+  pub power_ups: Option<f64>,
+  // a boss phrase appears every this many levels, 0 for none
+  pub boss_levels: Option<u32>,
+  pub level_up_words: Option<u32>,
+  pub levels_per_word: Option<u32>,
+  pub theme: Option<String>,
+  pub wordlist: Option<String>,
+  pub keys: Keys,
+  pub levels: Levels,
+  // when set, the difficulty always adapts to the player
+  pub adaptive: Option<Adaptive>,
+  pub matching: Matching,
+  pub selection: Selection,
+  pub spawning: Spawning,
+}
+
+/// Names of the keys bound to the game actions.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Keys {
+  pub stop: Option<String>,
+  pub remove_char: Option<String>,
+  pub clear_buffer: Option<String>,
+  pub pause: Option<String>,
+}
+
+/// Level progressions replacing the generated ones, per mode.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Levels {
+  pub classic: Option<Vec<Level>>,
+  pub sprint: Option<Vec<Level>>,
+  pub zen: Option<Vec<Level>>,
+  pub training: Option<Vec<Level>>,
+  pub text: Option<Vec<Level>>,
+}
+
+impl Levels {
+  pub fn for_mode(&self, mode: Mode) -> Option<&Vec<Level>> {
+    match mode {
+      Mode::Classic => self.classic.as_ref(),
+      Mode::Sprint => self.sprint.as_ref(),
+      Mode::Zen => self.zen.as_ref(),
+      Mode::Training => self.training.as_ref(),
+      Mode::Text => self.text.as_ref(),
+    }
+  }
+}
+
+impl Config {
+  /// Loads the configuration from the given file. A missing file is an empty configuration.
+  pub fn load(path: &Path) -> Result<Self, String> {
+    match fs::read_to_string(path) {
+      Ok(content) => Config::parse(&content)
+          .map_err(|error| format!("Invalid configuration {}: {}", path.display(), error)),
+      Err(_) if !path.exists() => Ok(Config::default()),
+      Err(error) => Err(format!("Could not read configuration {}: {}", path.display(), error)),
+    }
+  }
+
+  pub fn parse(content: &str) -> Result<Self, String> {
+    toml::from_str(content).map_err(|error| error.message().to_string())
+  }
+
+  /// the configuration file within the config directory of the user.
+  pub fn default_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("typeattack").join("config.toml"))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::adaptive::Adaptive;
+  use crate::config::{Config, Keys, Levels};
+  use crate::matching::Matching;
+  use crate::progression::Level;
+  use crate::selection::Selection;
+  use crate::spawning::Spawning;
+
+  #[test]
+  fn empty() {
+    assert_eq!(Config::parse("").unwrap(), Config::default());
+  }
+
+  #[test]
+  fn full() {
+    let config = Config::parse(r#"
+      lives = 5
+      speed = 0.0002
+      power_ups = 0.1
+      boss_levels = 3
+      level_up_words = 20
+      levels_per_word = 3
+      theme = "light"
+      wordlist = "german"
+
+      [keys]
+      stop = "tab"
+      remove_char = "delete"
+      clear_buffer = "enter"
+      pause = "f1"
+
+      [[levels.classic]]
+      speed = 1.0
+      words = 1
+      max_length = 5
+
+      [[levels.classic]]
+      speed = 1.5
+      words = 2
+      min_length = 3
+      lives_bonus = 1
+
+      [adaptive]
+      interval = 5000
+
+      [matching]
+      ignore_case = true
+      lock = true
+
+      [selection]
+      frequency = 1.0
+      recent = 50
+
+      [spawning]
+      interval = 1000
+      wave_words = 0
+    "#).unwrap();
+    assert_eq!(config, Config {
+      lives: Some(5),
+      speed: Some(0.0002),
+      power_ups: Some(0.1),
+      boss_levels: Some(3),
+      level_up_words: Some(20),
+      levels_per_word: Some(3),
+      theme: Some(String::from("light")),
+      wordlist: Some(String::from("german")),
+      keys: Keys {
+        stop: Some(String::from("tab")),
+        remove_char: Some(String::from("delete")),
+        clear_buffer: Some(String::from("enter")),
+        pause: Some(String::from("f1")),
+      },
+      levels: Levels {
+        classic: Some(vec![
+          Level { speed: 1.0, words: 1, min_length: 0, max_length: Some(5), lives_bonus: 0 },
+          Level { speed: 1.5, words: 2, min_length: 3, max_length: None, lives_bonus: 1 },
+        ]),
+        sprint: None,
+        zen: None,
+        training: None,
+        text: None,
+      },
+      adaptive: Some(Adaptive { interval: 5000, ..Adaptive::default() }),
+      matching: Matching { ignore_case: true, lock: true, ..Matching::default() },
+      selection: Selection { frequency: 1.0, recent: 50, ..Selection::default() },
+      spawning: Spawning { interval: 1000, wave_words: 0, ..Spawning::default() },
+    });
+  }
+
+  #[test]
+  fn invalid() {
+    assert!(Config::parse("lives = -1").is_err());
+    assert!(Config::parse("lives = \"many\"").is_err());
+    assert!(Config::parse("colour = \"red\"").is_err());
+  }
+}
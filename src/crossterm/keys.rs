@@ -0,0 +1,114 @@
+use crossterm::event::KeyCode;
+
+use crate::typeattack::Event;
+
+/// The keys that trigger the game events. Every other character is typed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KeyBindings {
+  pub stop: KeyCode,
+  pub remove_char: KeyCode,
+  pub clear_buffer: KeyCode,
+  pub pause: KeyCode,
+}
+
+impl KeyBindings {
+  /// Creates key bindings, every key can only be bound to one action.
+  /// Keys that type a character are needed for the words and can't be bound.
+  pub fn new(stop: KeyCode, remove_char: KeyCode, clear_buffer: KeyCode, pause: KeyCode) -> Result<Self, String> {
+    let keys = [stop, remove_char, clear_buffer, pause];
+    if keys.iter().enumerate().any(|(index, key)| keys[..index].contains(key)) {
+      return Err(String::from("Every key can only be bound to one action!"));
+    }
+    for key in keys {
+      if let KeyCode::Char(c) = key {
+        return Err(format!("The key {:?} types a character and can't be bound to an action!", c));
+      }
+    }
+    Ok(KeyBindings { stop, remove_char, clear_buffer, pause })
+  }
+
+  /// Creates key bindings from key names, see [parse_key].
+  pub fn parse(stop: &str, remove_char: &str, clear_buffer: &str, pause: &str) -> Result<Self, String> {
+    KeyBindings::new(parse_key(stop)?, parse_key(remove_char)?, parse_key(clear_buffer)?, parse_key(pause)?)
+  }
+
+  pub(crate) fn event(&self, code: KeyCode) -> Option<Event> {
+    match code {
+      code if code == self.stop => Some(Event::Stop),
+      code if code == self.remove_char => Some(Event::RemoveChar),
+      code if code == self.clear_buffer => Some(Event::ClearBuffer),
+      code if code == self.pause => Some(Event::Pause),
+      KeyCode::Char(c) => Some(Event::AddChar(c)),
+      _ => None
+    }
+  }
+}
+
+impl Default for KeyBindings {
+  fn default() -> Self {
+    KeyBindings {
+      stop: KeyCode::Esc,
+      remove_char: KeyCode::Backspace,
+      clear_buffer: KeyCode::Enter,
+      pause: KeyCode::Tab,
+    }
+  }
+}
+
+/// Parses the name of a key: esc, backspace, enter, tab, delete or f1 - f12.
+pub fn parse_key(name: &str) -> Result<KeyCode, String> {
+  match name.to_lowercase().as_str() {
+    "esc" | "escape" => Ok(KeyCode::Esc),
+    "backspace" => Ok(KeyCode::Backspace),
+    "enter" | "return" => Ok(KeyCode::Enter),
+    "tab" => Ok(KeyCode::Tab),
+    "delete" | "del" => Ok(KeyCode::Delete),
+    key => key.strip_prefix('f')
+        .and_then(|number| number.parse::<u8>().ok())
+        .filter(|number| (1..=12).contains(number))
+        .map(KeyCode::F)
+        .ok_or(format!("Unknown key {}", name))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crossterm::event::KeyCode;
+
+  use crate::crossterm::keys::{parse_key, KeyBindings};
+  use crate::typeattack::Event;
+
+  #[test]
+  fn key_names() {
+    assert_eq!(parse_key("esc"), Ok(KeyCode::Esc));
+    assert_eq!(parse_key("Enter"), Ok(KeyCode::Enter));
+    assert_eq!(parse_key("F5"), Ok(KeyCode::F(5)));
+    // keys that type a character have no name
+    assert!(parse_key("q").is_err());
+    assert!(parse_key("space").is_err());
+    assert!(parse_key("f13").is_err());
+    assert!(parse_key("hyper").is_err());
+  }
+
+  #[test]
+  fn events() {
+    let bindings = KeyBindings::parse("tab", "delete", "enter", "f1").unwrap();
+    assert_eq!(bindings.event(KeyCode::Tab), Some(Event::Stop));
+    assert_eq!(bindings.event(KeyCode::Delete), Some(Event::RemoveChar));
+    assert_eq!(bindings.event(KeyCode::F(1)), Some(Event::Pause));
+    assert_eq!(bindings.event(KeyCode::Char('x')), Some(Event::AddChar('x')));
+    assert_eq!(bindings.event(KeyCode::Esc), None);
+  }
+
+  #[test]
+  fn duplicate_bindings() {
+    assert!(KeyBindings::parse("esc", "esc", "enter", "tab").is_err());
+    assert!(KeyBindings::parse("esc", "backspace", "enter", "enter").is_err());
+  }
+
+  #[test]
+  fn typed_keys_cant_be_bound() {
+    assert!(KeyBindings::new(KeyCode::Char('q'), KeyCode::Backspace, KeyCode::Enter, KeyCode::Tab).is_err());
+    assert!(KeyBindings::new(KeyCode::Esc, KeyCode::Backspace, KeyCode::Enter, KeyCode::Char(' ')).is_err());
+  }
+}
@@ -1,22 +1,31 @@
+mod keys;
 mod strings;
+mod theme;
 
 use std::io::{stdout, Write};
 use std::pin::Pin;
 use std::sync::{Arc, Mutex};
 use strings::*;
+pub use keys::{parse_key, KeyBindings};
+pub use theme::Theme;
 
 use crossterm::{
   cursor::{Hide, MoveTo, RestorePosition, SavePosition},
-  event::{self, KeyCode},
+  event::{self, DisableFocusChange, EnableFocusChange, KeyEventKind},
   execute,
   queue,
-  style::{Color, Print, ResetColor, SetBackgroundColor, SetForegroundColor}, terminal::{Clear, ClearType, enable_raw_mode, size},
+  style::{Attribute, Color, Print, ResetColor, SetAttribute, SetBackgroundColor, SetForegroundColor}, terminal::{Clear, ClearType, enable_raw_mode, size},
 };
 use crossterm::cursor::EnableBlinking;
 use crossterm::terminal::disable_raw_mode;
 use futures::stream::{Stream, StreamExt};
 
-use crate::typeattack::{Event, RenderEngine, Word, WorldState};
+use crate::history::Session;
+use crate::statistics::Statistics;
+use crate::score;
+use crate::text;
+use crate::training::Heatmap;
+use crate::typeattack::{Event, RenderEngine, Word, WorldState, MAX_NAME_LENGTH};
 
 struct Screen {
   size_x: u16,
@@ -37,45 +46,82 @@ impl Screen {
 }
 
 pub struct Crossterm {
-  screen: Arc<Mutex<Screen>>
+  screen: Arc<Mutex<Screen>>,
+  theme: Theme,
+  keys: KeyBindings,
 }
 
 impl Crossterm {
   pub fn new() -> Self {
+    Crossterm::new_with_options(Theme::default(), KeyBindings::default())
+  }
+
+  pub fn new_with_options(theme: Theme, keys: KeyBindings) -> Self {
     let (x, y) = size().unwrap();
-    Crossterm::new_with_size(x, y)
+    let mut instance = Crossterm::new_with_size(x, y);
+    instance.theme = theme;
+    instance.keys = keys;
+    instance
   }
 
   pub fn new_with_size(x: u16, y: u16) -> Self {
     let instance = Crossterm {
-      screen: Arc::new(Mutex::new(Screen::new(0, 0)))
+      screen: Arc::new(Mutex::new(Screen::new(0, 0))),
+      theme: Theme::default(),
+      keys: KeyBindings::default(),
     };
     Self::set_screen_size(&instance.screen, x, y);
     instance
   }
 
-  fn print_word(self: &Self, buffer: &String, word: &Word) {
+  fn print_word(&self, state: &WorldState, word: &Word) {
     let (x, y) = self.get_position(word);
-    queue!(stdout(),
-      MoveTo(x, y),
-      Print(&word.word)
-    ).unwrap();
-
-    if word.word.starts_with(buffer.as_str()) {
-      queue!(
-        stdout(),
-        MoveTo(x, y),
-        SetForegroundColor(Color::Black),
-        SetBackgroundColor(Color::White),
-        Print(buffer),
-        ResetColor
+    let (size_x, size_y) = {
+      let screen = self.screen.lock().unwrap();
+      (screen.size_x, screen.size_y)
+    };
+    // power-ups stand out in bold and color
+    if word.power_up.is_some() {
+      queue!(stdout(), SetAttribute(Attribute::Bold)).unwrap();
+      if self.theme != Theme::PLAIN {
+        queue!(stdout(), SetForegroundColor(POWER_UP_COLOR)).unwrap();
+      }
+    }
+    // a phrase wider than the screen is wrapped, lines below the screen are cut off
+    let lines = text::wrap(&word.word, size_x as usize).into_iter().enumerate();
+    for (row, (start, line)) in lines.take_while(|(row, _)| y as usize + row < size_y as usize) {
+      queue!(stdout(), MoveTo(x, y + row as u16)).unwrap();
+      match state.typed_part(word) {
+        // the target shows the typed part reversed and the rest underlined
+        Some(typed) => {
+          let typed = typed.len().saturating_sub(start).min(line.len());
+          queue!(stdout(),
+            SetAttribute(Attribute::Reverse),
+            Print(&line[..typed]),
+            SetAttribute(Attribute::NoReverse),
+            SetAttribute(Attribute::Underlined),
+            Print(&line[typed..]),
+            SetAttribute(Attribute::NoUnderline)
+          ).unwrap()
+        }
+        None => queue!(stdout(), Print(line)).unwrap(),
+      }
+    }
+    if word.power_up.is_some() {
+      queue!(stdout(),
+        SetAttribute(Attribute::NormalIntensity),
+        SetForegroundColor(self.theme.foreground.unwrap_or(Color::Reset))
       ).unwrap();
     }
   }
 
-  fn get_position(self: &Self, word: &Word) -> (u16, u16) {
+
+  fn get_position(&self, word: &Word) -> (u16, u16) {
     let screen = self.screen.lock().unwrap();
-    let word_size = word.word.len() as f64 * screen.unit_x;
+    let width = text::wrap(&word.word, screen.size_x as usize).iter()
+        .map(|(_, line)| text::width(line))
+        .max().unwrap_or(0);
+    let word_size = width as f64 * screen.unit_x;
     let max = (screen.size_x as f64 * screen.unit_x) - word_size;
     // 1/max = value/x
     // x = max*value/1
@@ -84,53 +130,94 @@ impl Crossterm {
     (x.round() as u16, y.round() as u16)
   }
 
-  fn stream(self: &Self) -> Pin<Box<dyn Stream<Item=Event>>> {
+  fn stream(&self) -> Pin<Box<dyn Stream<Item=Event>>> {
     let screen = self.screen.clone();
-    return event::EventStream::new()
-        // filter all events we don't need
-        .filter(move |result| {
-          return futures::future::ready(match result {
-            Ok(event) => {
-              match event {
-                event::Event::Key(key) => {
-                  match key.code {
-                    KeyCode::Esc => true,
-                    KeyCode::Backspace => true,
-                    KeyCode::Char(_) => true,
-                    KeyCode::Enter => true,
-                    _ => false
-                  }
-                }
-                event::Event::Resize(x, y) => {
-                  Crossterm::set_screen_size(&screen, *x, *y);
-                  false
-                }
-                _ => false
-              }
-            }
-            _ => false
-          });
-        })
-        .map(|result| {
-          return match result {
-            Ok(event) => {
-              match event {
-                event::Event::Key(key) => {
-                  match key.code {
-                    KeyCode::Esc => Event::Stop,
-                    KeyCode::Backspace => Event::RemoveChar,
-                    KeyCode::Char(c) => Event::AddChar(c),
-                    KeyCode::Enter => Event::ClearBuffer,
-                    _ => panic!("")
-                  }
-                }
-                _ => panic!("")
-              }
+    let keys = self.keys;
+    event::EventStream::new()
+        // map the events we need, drop everything else
+        .filter_map(move |result| {
+          futures::future::ready(match result {
+            Ok(event::Event::Key(key)) if key.kind != KeyEventKind::Release => keys.event(key.code),
+            Ok(event::Event::FocusLost) => Some(Event::FocusLost),
+            Ok(event::Event::Resize(x, y)) => {
+              Crossterm::set_screen_size(&screen, x, y);
+              None
             }
-            _ => panic!("")
-          };
+            _ => None
+          })
         })
-        .boxed();
+        .boxed()
+  }
+
+  /// the number of lines the heatmap takes, including the empty line after it.
+  fn heatmap_height(heatmap: &Heatmap) -> u16 {
+    match heatmap.rows.iter().flatten().any(|(_, rate)| rate.is_some()) {
+      true => heatmap.rows.len() as u16 + 1,
+      false => 0,
+    }
+  }
+
+  /// prints the error rate of every key in its color, starting at line `from`.
+  fn print_heatmap(&self, screen: &Screen, heatmap: &Heatmap, from: u16) {
+    if Self::heatmap_height(heatmap) == 0 {
+      return;
+    }
+    for (index, row) in heatmap.rows.iter().enumerate() {
+      // every key takes 2 columns, shifted like the rows of a keyboard
+      let pos_x = (screen.size_x.saturating_sub(row.len() as u16 * 2) / 2) + index as u16;
+      queue!(stdout(), MoveTo(pos_x, from + index as u16)).unwrap();
+      for (key, rate) in row {
+        match (self.theme == Theme::PLAIN, rate) {
+          (true, Some(rate)) if *rate >= BAD_KEY_RATE => queue!(stdout(),
+            SetAttribute(Attribute::Reverse),
+            Print(key),
+            SetAttribute(Attribute::NoReverse),
+            Print(" ")
+          ).unwrap(),
+          (false, Some(rate)) => queue!(stdout(),
+            SetForegroundColor(heat_color(*rate)),
+            Print(key),
+            SetForegroundColor(self.theme.foreground.unwrap_or(Color::Reset)),
+            Print(" ")
+          ).unwrap(),
+          _ => queue!(stdout(), Print(key), Print(" ")).unwrap(),
+        }
+      }
+    }
+  }
+
+  /// the number of lines the high score table takes.
+  fn highscores_height(highscores: &[Session]) -> u16 {
+    match highscores.is_empty() {
+      true => 0,
+      false => highscores.len() as u16 + 1,
+    }
+  }
+
+  /// prints the high score table starting at line `from`, cutting it off before line `to`.
+  fn print_highscores(screen: &Screen, highscores: &[Session], from: u16, to: u16) {
+    if highscores.is_empty() {
+      return;
+    }
+    let header = format!("{:>3} {:<16} {:>7} {:>6} {:>6} {:>6} {:>9} {:>10}",
+      "#", "Name", "Score", "Words", "Level", "WPM", "Accuracy", "Date");
+    let pos_x = screen.size_x.saturating_sub(text::width(&header) as u16) / 2;
+    let lines = highscores.iter().enumerate().map(|(index, session)| {
+      let name = session.name.as_deref().unwrap_or("-");
+      format!("{:>3} {}{} {:>7} {:>6} {:>6} {:>6.1} {:>8.1}% {:>10}",
+        format!("{}.", index + 1),
+        name,
+        " ".repeat(MAX_NAME_LENGTH.saturating_sub(text::width(name))),
+        session.score,
+        session.words,
+        session.level,
+        session.wpm,
+        session.accuracy * 100.0,
+        session.date_string())
+    });
+    for (line, pos_y) in std::iter::once(header).chain(lines).zip(from..to) {
+      queue!(stdout(), MoveTo(pos_x, pos_y), Print(line)).unwrap();
+    }
   }
 
   fn set_screen_size(target: &Arc<Mutex<Screen>>, x: u16, y: u16) {
@@ -145,31 +232,77 @@ impl Crossterm {
   }
 }
 
+/// color of the words that trigger a power-up.
+const POWER_UP_COLOR: Color = Color::Magenta;
+
+/// keys with at least this error rate are shown as bad.
+const BAD_KEY_RATE: f64 = 0.1;
+
+/// the color of a key in the heatmap.
+fn heat_color(rate: f64) -> Color {
+  match rate {
+    rate if rate < 0.02 => Color::Green,
+    rate if rate < 0.05 => Color::Yellow,
+    rate if rate < BAD_KEY_RATE => Color::DarkYellow,
+    _ => Color::Red,
+  }
+}
+
+/// formats a duration in ms as minutes and seconds.
+fn format_duration(millis: u128) -> String {
+  let seconds = millis / 1000;
+  format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+/// formats an optional duration in ms as seconds.
+fn format_millis(millis: Option<f64>) -> String {
+  match millis {
+    Some(millis) => format!("{:.2}s", millis / 1000.0),
+    None => String::from("-")
+  }
+}
+
+impl Default for Crossterm {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
 impl RenderEngine for Crossterm {
-  fn init(self: &Self) -> Result<(), String> {
+  fn init(&self) -> Result<(), String> {
     enable_raw_mode().map_err(|_| "Terminal is not supported!")?;
+    if let Some(color) = self.theme.foreground {
+      queue!(stdout(), SetForegroundColor(color)).unwrap();
+    }
+    if let Some(color) = self.theme.background {
+      queue!(stdout(), SetBackgroundColor(color)).unwrap();
+    }
     execute!(stdout(),
-      SetForegroundColor(Color::White),
-      SetBackgroundColor(Color::Black),
       Hide,
-      SavePosition
+      SavePosition,
+      EnableFocusChange
     ).unwrap();
     Ok(())
   }
 
-  fn event_stream(self: &Self) -> Pin<Box<dyn Stream<Item=Event>>> {
+  fn event_stream(&self) -> Pin<Box<dyn Stream<Item=Event>>> {
     self.stream()
   }
 
-  fn draw_menu(self: &Self) {
+  fn draw_menu(&self, highscores: &[Session]) {
     let screen = self.screen.lock().unwrap();
-    let title_pos_y = (screen.size_y - 6) / 3;
+    // make room for the high score table
+    let title_pos_y = if highscores.is_empty() { (screen.size_y - 6) / 3 } else { 1 };
+    // the high score table ends above the copyright, the help stays above the table
+    let highscores_pos_y = screen.size_y - 2 - Self::highscores_height(highscores);
+    let help_pos_y = (title_pos_y + 8).min(highscores_pos_y - 2);
     let title_pos_x = (screen.size_x - INTRO1.len() as u16) / 2;
-    let help_pos_x = (screen.size_x - HELP.len() as u16) / 2;
-    let copyright_pos_x = (screen.size_x - COPYRIGHT.len() as u16) / 2;
-    execute!(stdout(),
+    let help = format!("{} - Leave the game  Any Key - Start the game", self.keys.stop);
+    let help_pos_x = screen.size_x.saturating_sub(text::width(&help) as u16) / 2;
+    let copyright_pos_x = (screen.size_x - text::width(COPYRIGHT) as u16) / 2;
+    queue!(stdout(),
       Clear(ClearType::All),
-      MoveTo(title_pos_x, title_pos_y + 0),
+      MoveTo(title_pos_x, title_pos_y),
       Print(INTRO1),
       MoveTo(title_pos_x, title_pos_y + 1),
       Print(INTRO2),
@@ -181,65 +314,199 @@ impl RenderEngine for Crossterm {
       Print(INTRO5),
       MoveTo(title_pos_x,title_pos_y + 5),
       Print(INTRO6),
-      MoveTo(help_pos_x,title_pos_y + 8),
-      Print(HELP),
+      MoveTo(help_pos_x, help_pos_y),
+      Print(help),
       MoveTo(copyright_pos_x, screen.size_y - 1),
       Print(COPYRIGHT)
     ).unwrap();
+    Self::print_highscores(&screen, highscores, highscores_pos_y, screen.size_y - 2);
+    // apply
+    stdout().flush().unwrap();
   }
 
-  fn draw_gamestate(self: &Self, state: &WorldState, _: &WorldState) {
+  fn draw_gamestate(&self, state: &WorldState, _: &WorldState) {
     queue!(stdout(), Clear(ClearType::All)).unwrap();
     // update new words
     for word in &state.words {
-      self.print_word(&state.buffer, &word);
+      self.print_word(state, word);
     }
 
     // draw HUD
+    let mut hud = format!("Score: {} x{} Level: {} Fails: {} Words: {} Inputs: {} Buffer: ",
+      &state.score,
+      score::multiplier(state.combo),
+      &state.level,
+      &state.fails,
+      &state.wordcount,
+      &state.keycount
+    );
+    if let Some(remaining) = state.remaining_time() {
+      // round up, so the countdown ends with 0:00
+      hud = format!("Time: {} {}", format_duration(remaining + 999), hud);
+    }
+    if let Some(reading) = state.reading {
+      hud = format!("Page: {}/{} {}", reading.page(), reading.pages(), hud);
+    }
+    // the progress on a boss phrase
+    if let Some(boss) = state.words.iter().find(|word| word.boss) {
+      let typed = state.typed_part(boss).map_or(0, |typed| typed.chars().count());
+      hud = format!("Boss: {}/{} {}", typed, boss.word.chars().count(), hud);
+    }
+    for effect in &state.effects {
+      hud = format!("[{} {}s] {}", effect.power_up.name(), (effect.until - state.time).div_ceil(1000), hud);
+    }
+    // a buffer with a rejected key is crossed out, until the key is removed
+    let buffer_attribute = match state.is_buffer_valid() {
+      true => Attribute::NotCrossedOut,
+      false => Attribute::CrossedOut,
+    };
     queue!(stdout(),
       MoveTo(0, self.screen.lock().unwrap().size_y),
-      Print(format!("Level: {} Fails: {} Words: {} Inputs: {} Buffer: {}",
-        &state.level,
-        &state.fails,
-        &state.wordcount,
-        &state.keycount,
-        &state.buffer
-        ))
+      Print(hud),
+      SetAttribute(buffer_attribute),
+      Print(&state.buffer),
+      SetAttribute(Attribute::NotCrossedOut)
       ).unwrap();
     // apply
     stdout().flush().unwrap();
   }
 
-  fn draw_result(self: &Self, _result: &WorldState) {
-    let gameover_pos_x = (self.screen.lock().unwrap().size_x - GAME_OVER1.len() as u16) / 2;
-    let gameover_pos_y = (self.screen.lock().unwrap().size_y-8) / 2;
+  fn screen_size(&self) -> (u16, u16) {
+    let screen = self.screen.lock().unwrap();
+    // the words are on all rows but the one of the HUD
+    (screen.size_x, screen.size_y - 1)
+  }
+
+  fn word_size(&self, word: &str, (columns, rows): (u16, u16)) -> (f64, f64) {
+    text::block_size(word, columns, rows)
+  }
+
+  fn draw_pause(&self, state: &WorldState) {
+    self.draw_gamestate(state, state);
+    let screen = self.screen.lock().unwrap();
+    let help = format!("{} - Resume  {} - Leave the game", self.keys.pause, self.keys.stop);
+    let pos_y = screen.size_y / 2;
     queue!(stdout(),
-      Clear(ClearType::All),
-      MoveTo(gameover_pos_x, gameover_pos_y + 0),
-      Print(GAME_OVER1),
-      MoveTo(gameover_pos_x, gameover_pos_y + 1),
-      Print(GAME_OVER2),
-      MoveTo(gameover_pos_x, gameover_pos_y + 2),
-      Print(GAME_OVER3),
-      MoveTo(gameover_pos_x, gameover_pos_y + 3),
-      Print(GAME_OVER4),
-      MoveTo(gameover_pos_x, gameover_pos_y + 4),
-      Print(GAME_OVER5),
-      MoveTo(gameover_pos_x, gameover_pos_y + 5),
-      Print(GAME_OVER6),
-      MoveTo(gameover_pos_x, gameover_pos_y + 6),
-      Print(GAME_OVER7),
-      MoveTo(gameover_pos_x, gameover_pos_y + 7),
-      Print(GAME_OVER8)
+      MoveTo((screen.size_x - PAUSE_TITLE.len() as u16) / 2, pos_y - 1),
+      SetAttribute(Attribute::Reverse),
+      Print(PAUSE_TITLE),
+      SetAttribute(Attribute::NoReverse),
+      MoveTo(screen.size_x.saturating_sub(text::width(&help) as u16) / 2, pos_y + 1),
+      Print(help)
+    ).unwrap();
+    // apply
+    stdout().flush().unwrap();
+  }
+
+  fn draw_result(&self, result: &WorldState, highscores: &[Session], heatmap: &Heatmap) {
+    let screen = self.screen.lock().unwrap();
+    let statistics = Statistics::new(result);
+    let mut lines = vec![
+      format!("Score: {}  Best combo: {}  Level: {}  Words: {}  Time: {}",
+        result.score,
+        result.best_combo,
+        result.level,
+        result.wordcount,
+        format_duration(statistics.duration)),
+      format!("WPM: {:.1} (net {:.1})  CPM: {:.0}  Accuracy: {:.1}%",
+        statistics.gross_wpm,
+        statistics.net_wpm,
+        statistics.cpm,
+        statistics.accuracy * 100.0),
+      format!("Time per word: {}  Reaction time: {}",
+        format_millis(statistics.time_per_word),
+        format_millis(statistics.reaction_time)),
+    ];
+    if let Some(reading) = result.reading {
+      lines.push(format!("Page {} of {}", reading.page(), reading.pages()));
+    }
+    if let Some(difficulty) = statistics.difficulty {
+      let harder = statistics.harder_adjustments();
+      let mut line = format!("Difficulty: {:+} ({} harder, {} easier)",
+        difficulty,
+        harder,
+        statistics.adjustments.len() - harder);
+      if let Some(last) = statistics.adjustments.last() {
+        line = format!("{}  Last change at {}: {:.0} WPM for {:.0} needed, {:.0}% missed",
+          line,
+          format_duration(last.time),
+          last.wpm,
+          last.demand_wpm,
+          last.miss_rate * 100.0);
+      }
+      lines.push(line);
+    }
+    let gameover_pos_x = (screen.size_x - GAME_OVER1.len() as u16) / 2;
+    let heatmap_height = Self::heatmap_height(heatmap);
+    let lines_pos_y = match highscores.is_empty() {
+      true => screen.size_y.saturating_sub(10 + lines.len() as u16 + heatmap_height) / 2 + 9,
+      // the high score table goes to the bottom and everything else above it
+      false => (screen.size_y - Self::highscores_height(highscores) - heatmap_height)
+          .saturating_sub(lines.len() as u16 + 1),
+    };
+    queue!(stdout(), Clear(ClearType::All)).unwrap();
+    // the banner is left out if it doesn't fit above the results
+    if lines_pos_y >= 9 {
+      let gameover_pos_y = lines_pos_y - 9;
+      queue!(stdout(),
+        MoveTo(gameover_pos_x, gameover_pos_y),
+        Print(GAME_OVER1),
+        MoveTo(gameover_pos_x, gameover_pos_y + 1),
+        Print(GAME_OVER2),
+        MoveTo(gameover_pos_x, gameover_pos_y + 2),
+        Print(GAME_OVER3),
+        MoveTo(gameover_pos_x, gameover_pos_y + 3),
+        Print(GAME_OVER4),
+        MoveTo(gameover_pos_x, gameover_pos_y + 4),
+        Print(GAME_OVER5),
+        MoveTo(gameover_pos_x, gameover_pos_y + 5),
+        Print(GAME_OVER6),
+        MoveTo(gameover_pos_x, gameover_pos_y + 6),
+        Print(GAME_OVER7),
+        MoveTo(gameover_pos_x, gameover_pos_y + 7),
+        Print(GAME_OVER8)
+      ).unwrap();
+    }
+    for (index, line) in lines.iter().enumerate() {
+      queue!(stdout(),
+        MoveTo(screen.size_x.saturating_sub(text::width(line) as u16) / 2, lines_pos_y + index as u16),
+        Print(line)
+      ).unwrap();
+    }
+    let heatmap_pos_y = lines_pos_y + 1 + lines.len() as u16;
+    self.print_heatmap(&screen, heatmap, heatmap_pos_y);
+    Self::print_highscores(&screen, highscores, heatmap_pos_y + heatmap_height, screen.size_y);
+    // apply
+    stdout().flush().unwrap();
+  }
 
+  fn draw_name_entry(&self, name: &str) {
+    let screen = self.screen.lock().unwrap();
+    let input = format!("{}{}", NAME_ENTRY_INPUT, name);
+    let help = format!("{} - Save  {} - Skip", self.keys.clear_buffer, self.keys.stop);
+    let pos_y = (screen.size_y - 5) / 2;
+    let input_pos_x = (screen.size_x - (NAME_ENTRY_INPUT.len() + MAX_NAME_LENGTH) as u16) / 2;
+    queue!(stdout(),
+      Clear(ClearType::All),
+      MoveTo((screen.size_x - NAME_ENTRY_TITLE.len() as u16) / 2, pos_y),
+      Print(NAME_ENTRY_TITLE),
+      MoveTo(input_pos_x, pos_y + 2),
+      Print(input),
+      SetAttribute(Attribute::Reverse),
+      Print(" "),
+      SetAttribute(Attribute::NoReverse),
+      MoveTo(screen.size_x.saturating_sub(text::width(&help) as u16) / 2, pos_y + 4),
+      Print(help)
     ).unwrap();
     // apply
     stdout().flush().unwrap();
   }
 
-  fn teardown(self: &Self) {
+  fn teardown(&self) {
     disable_raw_mode().unwrap();
     execute!(stdout(),
+      DisableFocusChange,
+      ResetColor,
       EnableBlinking,
       RestorePosition,
       Clear(ClearType::All),
@@ -317,4 +584,33 @@ mod tests {
     assert_eq!(x, 38);
     assert_eq!(y, 0);
   }
+
+  /// 0123456789
+  /// ....Über..
+  #[test]
+  fn text_right_umlaut() {
+    let crossterm = Crossterm::new_with_size(80, 24);
+    let word = Word::new("Über", 1., 0.);
+    let (x, _) = crossterm.get_position(&word);
+    assert_eq!(x, 76);
+  }
+
+  /// a phrase wider than the screen starts on the left
+  #[test]
+  fn phrase_is_wrapped() {
+    let crossterm = Crossterm::new_with_size(80, 24);
+    let word = Word::new(&["phrase"; 20].join(" "), 1., 0.);
+    let (x, _) = crossterm.get_position(&word);
+    assert_eq!(x, 4);
+  }
+
+  /// 0123456789
+  /// ......日本
+  #[test]
+  fn text_right_wide() {
+    let crossterm = Crossterm::new_with_size(80, 24);
+    let word = Word::new("日本", 1., 0.);
+    let (x, _) = crossterm.get_position(&word);
+    assert_eq!(x, 76);
+  }
 }
\ No newline at end of file
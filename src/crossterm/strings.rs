@@ -1,17 +1,19 @@
-pub const INTRO1: &'static str = ".%%%%%%..%%..%%..%%%%%...%%%%%%...%%%%...%%%%%%..%%%%%%...%%%%....%%%%...%%..%%.";
-pub const INTRO2: &'static str = "...%%.....%%%%...%%..%%..%%......%%..%%....%%......%%....%%..%%..%%..%%..%%.%%..";
-pub const INTRO3: &'static str = "...%%......%%....%%%%%...%%%%....%%%%%%....%%......%%....%%%%%%..%%......%%%%...";
-pub const INTRO4: &'static str = "...%%......%%....%%......%%......%%..%%....%%......%%....%%..%%..%%..%%..%%.%%..";
-pub const INTRO5: &'static str = "...%%......%%....%%......%%%%%%..%%..%%....%%......%%....%%..%%...%%%%...%%..%%.";
-pub const INTRO6: &'static str = ".............................Crossterm Edition..................................";
-pub const GAME_OVER1: &'static str = "   ********                                    *******                          ";
-pub const GAME_OVER2: &'static str = "  **//////**                                  **/////**                         ";
-pub const GAME_OVER3: &'static str = " **      //   ******   **********   *****    **     //** **    **  *****  ******";
-pub const GAME_OVER4: &'static str = "/**          //////** //**//**//** **///**  /**      /**/**   /** **///**//**//*";
-pub const GAME_OVER5: &'static str = "/**    *****  *******  /** /** /**/*******  /**      /**//** /** /******* /** / ";
-pub const GAME_OVER6: &'static str = "//**  ////** **////**  /** /** /**/**////   //**     **  //****  /**////  /**   ";
-pub const GAME_OVER7: &'static str = " //******** //******** *** /** /**//******   //*******    //**   //******/***   ";
-pub const GAME_OVER8: &'static str = "  ////////   //////// ///  //  //  //////     ///////      //     ////// ///    ";
-pub const HELP: &'static str = "Esc - Leave the game  Any Key - Start the game";
+pub const INTRO1: &str = ".%%%%%%..%%..%%..%%%%%...%%%%%%...%%%%...%%%%%%..%%%%%%...%%%%....%%%%...%%..%%.";
+pub const INTRO2: &str = "...%%.....%%%%...%%..%%..%%......%%..%%....%%......%%....%%..%%..%%..%%..%%.%%..";
+pub const INTRO3: &str = "...%%......%%....%%%%%...%%%%....%%%%%%....%%......%%....%%%%%%..%%......%%%%...";
+pub const INTRO4: &str = "...%%......%%....%%......%%......%%..%%....%%......%%....%%..%%..%%..%%..%%.%%..";
+pub const INTRO5: &str = "...%%......%%....%%......%%%%%%..%%..%%....%%......%%....%%..%%...%%%%...%%..%%.";
+pub const INTRO6: &str = ".............................Crossterm Edition..................................";
+pub const GAME_OVER1: &str = "   ********                                    *******                          ";
+pub const GAME_OVER2: &str = "  **//////**                                  **/////**                         ";
+pub const GAME_OVER3: &str = " **      //   ******   **********   *****    **     //** **    **  *****  ******";
+pub const GAME_OVER4: &str = "/**          //////** //**//**//** **///**  /**      /**/**   /** **///**//**//*";
+pub const GAME_OVER5: &str = "/**    *****  *******  /** /** /**/*******  /**      /**//** /** /******* /** / ";
+pub const GAME_OVER6: &str = "//**  ////** **////**  /** /** /**/**////   //**     **  //****  /**////  /**   ";
+pub const GAME_OVER7: &str = " //******** //******** *** /** /**//******   //*******    //**   //******/***   ";
+pub const GAME_OVER8: &str = "  ////////   //////// ///  //  //  //////     ///////      //     ////// ///    ";
+pub const NAME_ENTRY_TITLE: &str = "New high score!";
+pub const NAME_ENTRY_INPUT: &str = "Your name: ";
+pub const PAUSE_TITLE: &str = "  PAUSED  ";
 
-pub const COPYRIGHT: &'static str = "Copyright 2020 by André Tietz";
\ No newline at end of file
+pub const COPYRIGHT: &str = "Copyright 2020 by André Tietz";
\ No newline at end of file
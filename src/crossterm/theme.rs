@@ -0,0 +1,44 @@
+use std::str::FromStr;
+
+use crossterm::style::Color;
+
+/// Colors of the terminal. Highlighted text is shown in reversed colors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+  // [None] keeps the colors of the terminal
+  pub foreground: Option<Color>,
+  pub background: Option<Color>,
+}
+
+impl Theme {
+  pub const DARK: Theme = Theme { foreground: Some(Color::White), background: Some(Color::Black) };
+  pub const LIGHT: Theme = Theme { foreground: Some(Color::Black), background: Some(Color::White) };
+  pub const MATRIX: Theme = Theme { foreground: Some(Color::Green), background: Some(Color::Black) };
+  /// doesn't change any color, for terminals without color support.
+  pub const PLAIN: Theme = Theme { foreground: None, background: None };
+
+  /// the names of all themes.
+  pub fn names() -> Vec<&'static str> {
+    vec!["dark", "light", "matrix", "plain"]
+  }
+}
+
+impl Default for Theme {
+  fn default() -> Self {
+    Theme::DARK
+  }
+}
+
+impl FromStr for Theme {
+  type Err = String;
+
+  fn from_str(name: &str) -> Result<Self, Self::Err> {
+    match name {
+      "dark" => Ok(Theme::DARK),
+      "light" => Ok(Theme::LIGHT),
+      "matrix" => Ok(Theme::MATRIX),
+      "plain" => Ok(Theme::PLAIN),
+      _ => Err(format!("Unknown theme {}, use one of: {}", name, Theme::names().join(", ")))
+    }
+  }
+}
@@ -0,0 +1,147 @@
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::Poll;
+
+use futures::stream::{self, Stream};
+
+use crate::history::Session;
+use crate::text;
+use crate::training::Heatmap;
+use crate::typeattack::{Event, RenderEngine, WorldState, DEFAULT_SCREEN};
+
+/// Everything the game asked the [Headless] engine to draw, in order.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Frame {
+  Menu,
+  GameState(WorldState),
+  Pause(WorldState),
+  Result(WorldState),
+  NameEntry(String),
+}
+
+/// A [RenderEngine] without any output. It plays a scripted list of [Event]s and
+/// records every draw call as a [Frame], so that a game can be run without a terminal.
+///
+/// Clones share the script and the recorded frames, which allows keeping a handle
+/// while the game owns the engine.
+#[derive(Clone)]
+pub struct Headless {
+  events: Arc<Mutex<VecDeque<Event>>>,
+  frames: Arc<Mutex<Vec<Frame>>>,
+}
+
+impl Headless {
+  pub fn new(events: Vec<Event>) -> Self {
+    Headless {
+      events: Arc::new(Mutex::new(events.into())),
+      frames: Arc::new(Mutex::new(Vec::new())),
+    }
+  }
+
+  /// all frames drawn so far.
+  pub fn frames(&self) -> Vec<Frame> {
+    self.frames.lock().unwrap().clone()
+  }
+
+  /// the world states of all drawn game frames.
+  pub fn world_states(&self) -> Vec<WorldState> {
+    self.frames.lock().unwrap().iter()
+        .filter_map(|frame| match frame {
+          Frame::GameState(state) => Some(state.clone()),
+          _ => None
+        })
+        .collect()
+  }
+
+  fn record(&self, frame: Frame) {
+    self.frames.lock().unwrap().push(frame);
+  }
+}
+
+impl RenderEngine for Headless {
+  fn init(&self) -> Result<(), String> {
+    Ok(())
+  }
+
+  /// Every stream takes the next events of the shared script.
+  /// Once the script is empty, the stream ends.
+  fn event_stream(&self) -> Pin<Box<dyn Stream<Item=Event>>> {
+    let events = self.events.clone();
+    Box::pin(stream::poll_fn(move |_| Poll::Ready(events.lock().unwrap().pop_front())))
+  }
+
+  fn draw_menu(&self, _: &[Session]) {
+    self.record(Frame::Menu);
+  }
+
+  fn draw_gamestate(&self, state: &WorldState, _: &WorldState) {
+    self.record(Frame::GameState(state.clone()));
+  }
+
+  fn draw_pause(&self, state: &WorldState) {
+    self.record(Frame::Pause(state.clone()));
+  }
+
+  fn screen_size(&self) -> (u16, u16) {
+    DEFAULT_SCREEN
+  }
+
+  fn word_size(&self, word: &str, (columns, rows): (u16, u16)) -> (f64, f64) {
+    text::block_size(word, columns, rows)
+  }
+
+  fn draw_result(&self, result: &WorldState, _: &[Session], _: &Heatmap) {
+    self.record(Frame::Result(result.clone()));
+  }
+
+  fn draw_name_entry(&self, name: &str) {
+    self.record(Frame::NameEntry(String::from(name)));
+  }
+
+  fn teardown(&self) {}
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::clock::StepClock;
+  use crate::headless::{Frame, Headless};
+  use crate::typeattack::{Event, Typeattack};
+
+  #[test]
+  fn stop_in_menu_quits() {
+    let engine = Headless::new(vec![Event::Stop]);
+    Typeattack::new(Box::new(engine.clone())).start();
+    assert_eq!(engine.frames(), vec![Frame::Menu]);
+  }
+
+  #[test]
+  fn ended_script_quits() {
+    let engine = Headless::new(vec![]);
+    Typeattack::new(Box::new(engine.clone())).start();
+    assert_eq!(engine.frames(), vec![Frame::Menu]);
+  }
+
+  #[test]
+  fn rejected_key_is_counted() {
+    let engine = Headless::new(vec![
+      Event::AddChar('s'),
+      // the first word of seed 1 is "would", so the key is rejected
+      Event::AddChar('a'),
+      Event::Stop,
+      Event::AddChar('x'),
+      Event::Stop,
+    ]);
+    Typeattack::new_with_seed(Box::new(engine.clone()), 1, Box::new(StepClock::new(16))).start();
+
+    let frames = engine.frames();
+    assert_eq!(frames.last(), Some(&Frame::Menu));
+    match &frames[frames.len() - 2] {
+      Frame::Result(result) => {
+        assert_eq!((result.keycount, result.accepted, result.rejected), (1, 0, 1));
+        assert_eq!(result.words[0].word, "would");
+      }
+      frame => panic!("expected the result, got {:?}", frame),
+    }
+  }
+}
@@ -0,0 +1,238 @@
+use std::cmp::Ordering;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::statistics::Statistics;
+use crate::storage;
+use crate::typeattack::{Mode, WorldState};
+
+/// number of sessions in the high score table.
+pub const HIGHSCORE_SIZE: usize = 10;
+
+/// A finished game.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Session {
+  // seconds since the unix epoch
+  pub date: u64,
+  #[serde(default)]
+  pub mode: Mode,
+  // only sessions that made it into the high score table have a name
+  pub name: Option<String>,
+  pub level: u128,
+  pub words: u128,
+  #[serde(default)]
+  pub score: u64,
+  pub fails: u16,
+  pub wpm: f64,
+  pub accuracy: f64,
+}
+
+impl Session {
+  pub fn new(result: &WorldState, mode: Mode, date: u64) -> Self {
+    let statistics = Statistics::new(result);
+    Session {
+      date,
+      mode,
+      name: None,
+      level: result.level,
+      words: result.wordcount,
+      score: result.score,
+      fails: result.fails,
+      wpm: statistics.net_wpm,
+      accuracy: statistics.accuracy,
+    }
+  }
+
+  /// the date formatted as yyyy-mm-dd (UTC).
+  pub fn date_string(&self) -> String {
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let days = (self.date / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!("{:04}-{:02}-{:02}", year, month, day)
+  }
+
+  /// better sessions are ordered first. A classic game is about the score,
+  /// a sprint about the speed.
+  fn rank(&self, other: &Session) -> Ordering {
+    let score = other.score.cmp(&self.score);
+    let words = other.words.cmp(&self.words);
+    let wpm = other.wpm.total_cmp(&self.wpm);
+    match self.mode {
+      Mode::Classic | Mode::Zen | Mode::Training | Mode::Text => score.then(words).then(wpm),
+      Mode::Sprint => wpm.then(score).then(words),
+    }.then(self.date.cmp(&other.date))
+  }
+}
+
+/// All finished sessions. When created with a path, the sessions are stored on disk.
+pub struct History {
+  path: Option<PathBuf>,
+  sessions: Vec<Session>,
+}
+
+impl History {
+  /// A history that forgets all sessions when the game ends.
+  pub fn new() -> Self {
+    History {
+      path: None,
+      sessions: vec![],
+    }
+  }
+
+  /// Loads the history from the given file. A missing file is an empty history.
+  pub fn load(path: PathBuf) -> Result<Self, String> {
+    let sessions = storage::load_json(&path, "history")?;
+    Ok(History {
+      path: Some(path),
+      sessions,
+    })
+  }
+
+  /// the history file within the data directory of the user.
+  pub fn default_path() -> Option<PathBuf> {
+    storage::data_file("history.json")
+  }
+
+  pub fn save(&self) -> Result<(), String> {
+    let path = match &self.path {
+      Some(path) => path,
+      None => return Ok(())
+    };
+    storage::save_json(path, "history", &self.sessions)
+  }
+
+  pub fn add(&mut self, session: Session) {
+    self.sessions.push(session);
+  }
+
+  pub fn sessions(&self) -> &[Session] {
+    &self.sessions
+  }
+
+  /// the best sessions of a mode, best first.
+  pub fn highscores(&self, mode: Mode) -> Vec<Session> {
+    let mut sessions: Vec<Session> = self.sessions.iter()
+        .filter(|session| session.mode == mode)
+        .cloned()
+        .collect();
+    sessions.sort_by(Session::rank);
+    sessions.truncate(HIGHSCORE_SIZE);
+    sessions
+  }
+
+  /// true, if the session would make it into the high score table.
+  pub fn is_highscore(&self, session: &Session) -> bool {
+    if session.words == 0 {
+      return false;
+    }
+    let highscores = self.highscores(session.mode);
+    highscores.len() < HIGHSCORE_SIZE
+        || highscores.iter().any(|highscore| session.rank(highscore) == Ordering::Less)
+  }
+
+  /// the name of the most recent high score, to suggest it for the next one.
+  pub fn last_name(&self) -> Option<String> {
+    self.sessions.iter().rev().find_map(|session| session.name.clone())
+  }
+}
+
+impl Default for History {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+/// seconds since the unix epoch.
+pub fn now() -> u64 {
+  SystemTime::now().duration_since(UNIX_EPOCH).map(|duration| duration.as_secs()).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+  use std::env::temp_dir;
+  use std::fs;
+
+  use crate::history::{History, Session, HIGHSCORE_SIZE};
+  use crate::typeattack::Mode;
+
+  fn session(words: u128, wpm: f64) -> Session {
+    Session { date: 0, mode: Mode::Classic, name: None, level: 1, words, score: 0, fails: 3, wpm, accuracy: 1.0 }
+  }
+
+  #[test]
+  fn date_string() {
+    assert_eq!(session(0, 0.0).date_string(), "1970-01-01");
+    assert_eq!(Session { date: 951_782_400, ..session(0, 0.0) }.date_string(), "2000-02-29");
+    assert_eq!(Session { date: 1_792_281_600, ..session(0, 0.0) }.date_string(), "2026-10-18");
+  }
+
+  #[test]
+  fn highscores_are_sorted() {
+    let mut history = History::new();
+    history.add(session(5, 20.0));
+    history.add(session(10, 10.0));
+    history.add(session(5, 30.0));
+    let words: Vec<(u128, f64)> = history.highscores(Mode::Classic).iter().map(|s| (s.words, s.wpm)).collect();
+    assert_eq!(words, vec![(10, 10.0), (5, 30.0), (5, 20.0)]);
+  }
+
+  #[test]
+  fn score_comes_first() {
+    let mut history = History::new();
+    history.add(session(10, 10.0));
+    history.add(Session { score: 500, ..session(5, 10.0) });
+    let words: Vec<u128> = history.highscores(Mode::Classic).iter().map(|s| s.words).collect();
+    assert_eq!(words, vec![5, 10]);
+  }
+
+  #[test]
+  fn sprints_are_ranked_by_speed() {
+    let mut history = History::new();
+    history.add(Session { mode: Mode::Sprint, ..session(20, 40.0) });
+    history.add(Session { mode: Mode::Sprint, ..session(10, 60.0) });
+    history.add(session(30, 10.0));
+    let sprints: Vec<(u128, f64)> = history.highscores(Mode::Sprint).iter().map(|s| (s.words, s.wpm)).collect();
+    assert_eq!(sprints, vec![(10, 60.0), (20, 40.0)]);
+    assert_eq!(history.highscores(Mode::Classic).len(), 1);
+  }
+
+  #[test]
+  fn full_table_needs_a_better_session() {
+    let mut history = History::new();
+    for _ in 0..HIGHSCORE_SIZE {
+      history.add(session(5, 20.0));
+    }
+    assert_eq!(history.highscores(Mode::Classic).len(), HIGHSCORE_SIZE);
+    assert!(!history.is_highscore(&session(5, 20.0)));
+    assert!(!history.is_highscore(&session(4, 50.0)));
+    assert!(history.is_highscore(&session(5, 21.0)));
+    assert!(history.is_highscore(&session(6, 1.0)));
+  }
+
+  #[test]
+  fn empty_session_is_no_highscore() {
+    assert!(!History::new().is_highscore(&session(0, 0.0)));
+  }
+
+  #[test]
+  fn save_and_load() {
+    let path = temp_dir().join("typeattack-history").join("history.json");
+    let _ = fs::remove_file(&path);
+    let mut history = History::load(path.clone()).unwrap();
+    assert!(history.sessions().is_empty());
+    history.add(Session { name: Some(String::from("André")), ..session(3, 12.5) });
+    history.save().unwrap();
+    let loaded = History::load(path).unwrap();
+    assert_eq!(loaded.sessions(), history.sessions());
+    assert_eq!(loaded.last_name(), Some(String::from("André")));
+  }
+}
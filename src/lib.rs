@@ -0,0 +1,23 @@
+pub mod adaptive;
+pub mod clock;
+pub mod config;
+pub mod crossterm;
+pub mod headless;
+pub mod history;
+pub mod matching;
+pub mod placement;
+pub mod powerup;
+pub mod progression;
+pub mod reading;
+pub mod recording;
+pub mod score;
+pub mod selection;
+pub mod source;
+pub mod spawning;
+pub mod statistics;
+pub mod storage;
+pub mod text;
+pub mod training;
+pub mod typeattack;
+pub mod wordlist;
+mod words;
@@ -1,11 +1,338 @@
-use crate::crossterm::Crossterm;
-use crate::typeattack::Typeattack;
+use std::path::{Path, PathBuf};
 
-mod typeattack;
-mod crossterm;
-mod words;
+use clap::{Parser, Subcommand};
+use typeattack::adaptive::Adaptive;
+use typeattack::clock::SystemClock;
+use crossterm::event::KeyCode;
+use typeattack::config::Config;
+use typeattack::crossterm::{parse_key, Crossterm, KeyBindings, Theme};
+use typeattack::history::{History, Session};
+use typeattack::matching::Matching;
+use typeattack::powerup::DEFAULT_POWER_UP_CHANCE;
+use typeattack::progression::Progression;
+use typeattack::reading::Bookmarks;
+use typeattack::recording::Recording;
+use typeattack::text;
+use typeattack::training::Training;
+use typeattack::typeattack::{
+  Mode, Settings, Typeattack, DEFAULT_BOSS_LEVELS, DEFAULT_LEVELS_PER_WORD, DEFAULT_LEVEL_UP_WORDS, DEFAULT_LIVES, DEFAULT_SPEED,
+  DEFAULT_SCREEN, MAX_NAME_LENGTH,
+};
+use typeattack::wordlist::WordList;
+
+/// A small game to practice typing.
+///
+/// Defaults can be changed in config.toml within the config directory of the user.
+#[derive(Parser)]
+#[command(version)]
+struct Cli {
+  #[command(subcommand)]
+  command: Option<Command>,
+
+  /// rules of the game: classic (until all lives are lost), sprint (a timed typing test), zen (no pressure, Esc ends it),
+  /// training (like zen, with more words containing your weakest keys) or text (the words of --text in order)
+  #[arg(long, default_value = "classic")]
+  mode: Mode,
+
+  /// name of a bundled word pack (english, german, french, spanish, polish, rust, python, go, javascript, shell),
+  /// a word list file or a directory with source code to take the identifiers from [default: english]
+  #[arg(long)]
+  wordlist: Option<String>,
+
+  /// a text file to type through in text mode, - reads it from stdin. It continues where the last game stopped
+  #[arg(long)]
+  text: Option<PathBuf>,
+
+  /// starts the text from the beginning
+  #[arg(long)]
+  restart: bool,
+
+  /// seed for the words, every game with the same seed and input plays the same
+  #[arg(long)]
+  seed: Option<u64>,
+
+  /// number of words that may hit the ground before the game is over [default: 3]
+  #[arg(long, value_parser = clap::value_parser!(u16).range(1..))]
+  lives: Option<u16>,
+
+  /// level to start the game on
+  #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..))]
+  start_level: u16,
+
+  /// ends the game after this many seconds, a sprint lasts 60 seconds by default
+  #[arg(long)]
+  duration: Option<u64>,
+
+  /// maximum number of words on screen at the same time
+  #[arg(long, value_parser = clap::value_parser!(u16).range(1..))]
+  max_words: Option<u16>,
+
+  /// adapts speed, number and length of the words to the player, see [adaptive] in config.toml
+  #[arg(long)]
+  adaptive: bool,
+
+  /// chance of a new word being a power-up, between 0 and 1 [default: 0.05]
+  #[arg(long)]
+  power_ups: Option<f64>,
+
+  /// a boss phrase appears every this many levels, 0 for none [default: 5]
+  #[arg(long)]
+  boss_levels: Option<u32>,
+
+  /// rules for matching the typed text, comma separated: strict, ignore-case, ignore-accents, lock, submit
+  #[arg(long)]
+  matching: Option<Matching>,
+
+  /// colors of the game: dark, light, matrix, plain [default: dark]
+  #[arg(long)]
+  theme: Option<Theme>,
+
+  /// don't change the colors of the terminal
+  #[arg(long)]
+  no_color: bool,
+
+  /// records every game into this file, only the latest game is kept
+  #[arg(long)]
+  record: Option<PathBuf>,
+
+  /// prints the statistics of all played games when leaving the game
+  #[arg(long)]
+  stats: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+  /// prints all finished games
+  Stats,
+  /// plays back a recorded game
+  Replay {
+    file: PathBuf,
+  },
+}
 
 fn main() {
-  let mut typotack = Typeattack::new(Box::new(Crossterm::new()));
+  let cli = Cli::parse();
+  let result = match &cli.command {
+    None => play(&cli),
+    Some(Command::Stats) => stats(),
+    Some(Command::Replay { file }) => replay(file),
+  };
+  if let Err(error) = result {
+    println!("{}", error);
+  }
+}
+
+fn play(cli: &Cli) -> Result<(), String> {
+  let config = load_config()?;
+  let mode = match (&cli.text, cli.mode) {
+    (Some(_), _) => Mode::Text,
+    (None, Mode::Text) => return Err(String::from("The text mode needs a text, use --text <FILE>")),
+    (None, mode) => mode,
+  };
+  let words = match &cli.text {
+    Some(path) => load_text(path)?,
+    None => WordList::find(cli.wordlist.as_deref().or(config.wordlist.as_deref()).unwrap_or("english"))?,
+  };
+  // the game is created later, with the position from the bookmarks
+  let mut bookmarks_warning = None;
+  let bookmarks = load_bookmarks().unwrap_or_else(|error| {
+    bookmarks_warning = Some(error);
+    Bookmarks::new()
+  });
+  let position = match cli.restart {
+    true => 0,
+    false => bookmarks.get(&words),
+  };
+  let settings = Settings {
+    mode,
+    words,
+    lives: cli.lives.or(config.lives).unwrap_or(DEFAULT_LIVES),
+    start_level: cli.start_level as usize,
+    duration: cli.duration.map(|seconds| seconds as u128 * 1000),
+    speed: config.speed.unwrap_or(DEFAULT_SPEED),
+    level_up_words: config.level_up_words.map(u128::from).unwrap_or(DEFAULT_LEVEL_UP_WORDS),
+    levels_per_word: config.levels_per_word.map(|levels| levels as usize).unwrap_or(DEFAULT_LEVELS_PER_WORD),
+    max_words: cli.max_words.map(usize::from),
+    progression: config.levels.for_mode(mode)
+        .map(|levels| Progression::new(levels.clone()))
+        .transpose()
+        .map_err(|error| format!("Invalid levels for {:?}: {}", mode, error))?,
+    adaptive: match (cli.adaptive, config.adaptive) {
+      (true, None) => Some(Adaptive::default()),
+      (_, adaptive) => adaptive,
+    },
+    matching: cli.matching.unwrap_or(config.matching),
+    power_ups: cli.power_ups.or(config.power_ups).unwrap_or(DEFAULT_POWER_UP_CHANCE),
+    position,
+    selection: config.selection,
+    spawning: Some(config.spawning),
+    boss_levels: cli.boss_levels.or(config.boss_levels).map(|levels| levels as usize).unwrap_or(DEFAULT_BOSS_LEVELS),
+    // measured when a game starts
+    screen: DEFAULT_SCREEN,
+  };
+  let theme = match (cli.no_color, cli.theme, &config.theme) {
+    (true, _, _) => Theme::PLAIN,
+    (false, Some(theme), _) => theme,
+    (false, None, Some(name)) => name.parse()?,
+    (false, None, None) => Theme::default(),
+  };
+  let default_keys = KeyBindings::default();
+  let keys = KeyBindings::new(
+    key_or(&config.keys.stop, default_keys.stop)?,
+    key_or(&config.keys.remove_char, default_keys.remove_char)?,
+    key_or(&config.keys.clear_buffer, default_keys.clear_buffer)?,
+    key_or(&config.keys.pause, default_keys.pause)?,
+  )?;
+  let mut typotack = Typeattack::new_with_settings(
+    Box::new(Crossterm::new_with_options(theme, keys)),
+    settings,
+    cli.seed,
+    Box::new(SystemClock::new()),
+  );
+  let history = load_history().unwrap_or_else(|error| {
+    typotack.warn(error);
+    History::new()
+  });
+  let played = history.sessions().len();
+  typotack.use_history(history);
+  let training = load_training().unwrap_or_else(|error| {
+    typotack.warn(error);
+    Training::new()
+  });
+  typotack.use_training(training);
+  typotack.use_bookmarks(bookmarks);
+  if let Some(warning) = bookmarks_warning {
+    typotack.warn(warning);
+  }
+  if let Some(path) = &cli.record {
+    typotack.record_to(path.clone());
+  }
   typotack.start();
+  if cli.stats {
+    print_sessions(&typotack.history().sessions()[played..]);
+  }
+  Ok(())
+}
+
+fn stats() -> Result<(), String> {
+  let history = load_history()?;
+  print_sessions(history.sessions());
+  print_weaknesses(&load_training()?);
+  Ok(())
+}
+
+fn replay(file: &Path) -> Result<(), String> {
+  let recording = Recording::load(file)?;
+  let mut typotack = Typeattack::new_with_settings(
+    Box::new(Crossterm::new()),
+    recording.settings.clone(),
+    Some(recording.seed),
+    Box::new(SystemClock::new()),
+  );
+  typotack.replay(&recording);
+  Ok(())
+}
+
+/// the configuration in the config directory of the user, if there is one.
+fn load_config() -> Result<Config, String> {
+  match Config::default_path() {
+    Some(path) => Config::load(&path),
+    None => Ok(Config::default())
+  }
+}
+
+/// the key with the given name, or the default if there is no name.
+fn key_or(name: &Option<String>, default: KeyCode) -> Result<KeyCode, String> {
+  match name {
+    Some(name) => parse_key(name),
+    None => Ok(default)
+  }
+}
+
+/// the history in the data directory of the user, if there is one.
+fn load_history() -> Result<History, String> {
+  match History::default_path() {
+    Some(path) => History::load(path),
+    None => Ok(History::new())
+  }
+}
+
+/// the training statistics in the data directory of the user, if there is one.
+fn load_training() -> Result<Training, String> {
+  match Training::default_path() {
+    Some(path) => Training::load(path),
+    None => Ok(Training::new())
+  }
+}
+
+/// the bookmarks of the texts in the data directory of the user, if there is one.
+fn load_bookmarks() -> Result<Bookmarks, String> {
+  match Bookmarks::default_path() {
+    Some(path) => Bookmarks::load(path),
+    None => Ok(Bookmarks::new())
+  }
+}
+
+/// the words of the text in the file, or of stdin for -.
+fn load_text(path: &Path) -> Result<WordList, String> {
+  let (name, content) = match path.to_str() {
+    Some("-") => (String::from("stdin"), std::io::read_to_string(std::io::stdin())
+        .map_err(|error| format!("Could not read the text from stdin: {}", error))?),
+    _ => (path.file_stem().map(|name| name.to_string_lossy().to_string()).unwrap_or_default(), std::fs::read_to_string(path)
+        .map_err(|error| format!("Could not read text {}: {}", path.display(), error))?),
+  };
+  WordList::from_text(&name, &content)
+}
+
+fn print_weaknesses(training: &Training) {
+  let keys = training.weakest_keys();
+  if !keys.is_empty() {
+    println!();
+    println!("Weakest keys: {}", keys.iter()
+        .map(|(key, rate)| format!("{} {:.0}%", key, rate * 100.0))
+        .collect::<Vec<String>>().join("  "));
+  }
+  let bigrams = training.weakest_bigrams();
+  if !bigrams.is_empty() {
+    println!("Weakest bigrams: {}", bigrams.iter()
+        .map(|(bigram, rate)| format!("{} {:.0}%", bigram, rate * 100.0))
+        .collect::<Vec<String>>().join("  "));
+  }
+  let words = training.slowest_words();
+  if !words.is_empty() {
+    println!("Slowest words: {}", words.iter()
+        .map(|(word, time)| format!("{} {:.0}ms/char", word, time))
+        .collect::<Vec<String>>().join("  "));
+  }
+}
+
+fn print_sessions(sessions: &[Session]) {
+  if sessions.is_empty() {
+    println!("No games played yet.");
+    return;
+  }
+  println!("{:<10} {:<8} {:<16} {:>7} {:>6} {:>6} {:>6} {:>6} {:>9}",
+    "Date", "Mode", "Name", "Score", "Level", "Words", "Fails", "WPM", "Accuracy");
+  for session in sessions {
+    let name = session.name.as_deref().unwrap_or("-");
+    // wide characters take two columns
+    println!("{:<10} {:<8} {}{} {:>7} {:>6} {:>6} {:>6} {:>6.1} {:>8.1}%",
+      session.date_string(),
+      format!("{:?}", session.mode),
+      name,
+      " ".repeat(MAX_NAME_LENGTH.saturating_sub(text::width(name))),
+      session.score,
+      session.level,
+      session.words,
+      session.fails,
+      session.wpm,
+      session.accuracy * 100.0);
+  }
+  let count = sessions.len() as f64;
+  println!();
+  println!("Games: {}  Words: {}  Average WPM: {:.1}  Average accuracy: {:.1}%",
+    sessions.len(),
+    sessions.iter().map(|session| session.words).sum::<u128>(),
+    sessions.iter().map(|session| session.wpm).sum::<f64>() / count,
+    sessions.iter().map(|session| session.accuracy).sum::<f64>() / count * 100.0);
 }
@@ -0,0 +1,107 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+/// The rules for matching the typed text with the words on screen.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Matching {
+  // rejected keys stay in the buffer and need to be removed before the word can be finished
+  pub strict: bool,
+  pub ignore_case: bool,
+  // "e" matches "é", "u" matches "ü"
+  pub ignore_accents: bool,
+  // once the typed text fits only one word, other words don't match anymore
+  pub lock: bool,
+  // a word is only finished by pressing space or enter
+  pub submit: bool,
+}
+
+impl Matching {
+  /// the names of all rules.
+  pub fn names() -> Vec<&'static str> {
+    vec!["strict", "ignore-case", "ignore-accents", "lock", "submit"]
+  }
+
+  /// true, if the word starts with the typed text.
+  pub fn is_prefix(&self, word: &str, typed: &str) -> bool {
+    let mut word = self.fold(word);
+    self.fold(typed).all(|c| word.next() == Some(c))
+  }
+
+  /// true, if the typed text is the word.
+  pub fn is_complete(&self, word: &str, typed: &str) -> bool {
+    self.fold(word).eq(self.fold(typed))
+  }
+
+  /// the decomposed form of the text, without everything that should be ignored.
+  fn fold<'a>(&self, text: &'a str) -> Box<dyn Iterator<Item=char> + 'a> {
+    let ignore_accents = self.ignore_accents;
+    let chars = text.nfd().filter(move |c| !(ignore_accents && is_combining_mark(*c)));
+    match self.ignore_case {
+      true => Box::new(chars.flat_map(char::to_lowercase)),
+      false => Box::new(chars),
+    }
+  }
+}
+
+impl FromStr for Matching {
+  type Err = String;
+
+  /// parses a comma separated list of rules, e.g. "strict,ignore-case".
+  fn from_str(names: &str) -> Result<Self, Self::Err> {
+    let mut matching = Matching::default();
+    for name in names.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+      match name {
+        "strict" => matching.strict = true,
+        "ignore-case" => matching.ignore_case = true,
+        "ignore-accents" => matching.ignore_accents = true,
+        "lock" => matching.lock = true,
+        "submit" => matching.submit = true,
+        _ => return Err(format!("Unknown matching rule {}, use any of: {}", name, Matching::names().join(", ")))
+      }
+    }
+    Ok(matching)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::matching::Matching;
+
+  #[test]
+  fn exact() {
+    let matching = Matching::default();
+    assert!(matching.is_prefix("über", "u"));
+    assert!(!matching.is_prefix("über", "ub"));
+    assert!(!matching.is_prefix("Test", "t"));
+    assert!(matching.is_complete("café", "cafe\u{301}"));
+    assert!(!matching.is_complete("café", "cafe"));
+  }
+
+  #[test]
+  fn ignore_case() {
+    let matching = Matching { ignore_case: true, ..Matching::default() };
+    assert!(matching.is_prefix("Test", "t"));
+    assert!(matching.is_complete("Größe", "gRÖßE"));
+    assert!(!matching.is_complete("café", "CAFE"));
+  }
+
+  #[test]
+  fn ignore_accents() {
+    let matching = Matching { ignore_accents: true, ..Matching::default() };
+    assert!(matching.is_prefix("über", "ub"));
+    assert!(matching.is_complete("café", "cafe"));
+    assert!(matching.is_complete("café", "café"));
+    assert!(!matching.is_complete("Café", "cafe"));
+  }
+
+  #[test]
+  fn parse() {
+    assert_eq!("".parse::<Matching>().unwrap(), Matching::default());
+    assert_eq!("strict, lock".parse::<Matching>().unwrap(), Matching { strict: true, lock: true, ..Matching::default() });
+    assert!("fuzzy".parse::<Matching>().is_err());
+  }
+}
@@ -0,0 +1,93 @@
+/// The horizontal span a word of the given width covers at `x`, in screen units.
+/// Like on screen, `x` moves the word from the left edge (0.0) to the right edge (1.0).
+pub fn span(x: f64, width: f64) -> (f64, f64) {
+  let start = x * (1.0 - width).max(0.0);
+  (start, start + width)
+}
+
+/// The `x` of a new word of the given width, so that it doesn't overlap any of the taken spans.
+/// `random` within 0..1 picks one of the free places. The word moves next to a taken span if the space
+/// left between them would be narrower than the word, so that the space left over fits more words.
+/// If there is no free place, the word may go anywhere.
+pub fn place(random: f64, width: f64, taken: &[(f64, f64)]) -> f64 {
+  let room = (1.0 - width).max(0.0);
+  if room == 0.0 {
+    return random;
+  }
+  // the starts overlapping a taken span, sorted and merged
+  let mut blocked: Vec<(f64, f64)> = taken.iter()
+      .map(|(start, end)| ((start - width).max(0.0), end.min(room)))
+      .filter(|(start, end)| start < end)
+      .collect();
+  blocked.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+  let mut free = Vec::new();
+  let mut from = 0.0;
+  for (start, end) in blocked {
+    if start > from {
+      free.push((from, start));
+    }
+    from = f64::max(from, end);
+  }
+  if room > from {
+    free.push((from, room));
+  }
+  let total: f64 = free.iter().map(|(start, end)| end - start).sum();
+  if total <= 0.0 {
+    return random;
+  }
+  let mut offset = random * total;
+  for (start, end) in &free {
+    if offset <= end - start {
+      let position = start + offset;
+      let position = match (position - start < width, end - position < width) {
+        (true, true) if position - start > end - position => *end,
+        (true, _) => *start,
+        (false, true) => *end,
+        (false, false) => position,
+      };
+      return position / room;
+    }
+    offset -= end - start;
+  }
+  free.last().map_or(random, |(_, end)| end / room)
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::placement::{place, span};
+
+  #[test]
+  fn spans() {
+    assert_eq!(span(0.0, 0.25), (0.0, 0.25));
+    assert_eq!(span(1.0, 0.25), (0.75, 1.0));
+    assert_eq!(span(0.5, 1.5), (0.0, 1.5));
+  }
+
+  #[test]
+  fn free_screen() {
+    assert_eq!(place(0.5, 0.25, &[]), 0.5);
+    // no room for a word at the edges
+    assert_eq!(place(0.2, 0.25, &[]), 0.0);
+    assert_eq!(place(0.9, 0.25, &[]), 1.0);
+  }
+
+  #[test]
+  fn taken_spans_are_skipped() {
+    // only the starts 0.0..0.1 and 0.6..0.75 are free
+    let taken = [(0.35, 0.6)];
+    for random in [0.0, 0.2, 0.5, 0.8, 1.0] {
+      let (start, end) = span(place(random, 0.25, &taken), 0.25);
+      assert!(end <= 0.35 + 1e-9 || start >= 0.6 - 1e-9, "{} overlaps at {}..{}", random, start, end);
+    }
+    assert_eq!(place(0.0, 0.25, &taken), 0.0);
+    assert_eq!(place(1.0, 0.25, &taken), 1.0);
+    // a word in the middle of the hole would leave no room for another one
+    assert_eq!(span(place(0.4, 0.2, &[(0.0, 0.5)]), 0.2), (0.5, 0.7));
+  }
+
+  #[test]
+  fn full_screen_goes_anywhere() {
+    assert_eq!(place(0.4, 0.25, &[(0.0, 0.5), (0.4, 1.0)]), 0.4);
+    assert_eq!(place(0.4, 1.0, &[]), 0.4);
+  }
+}
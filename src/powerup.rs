@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+
+/// chance of a new word being a power-up, if nothing else is set.
+pub const DEFAULT_POWER_UP_CHANCE: f64 = 0.05;
+/// factor on the speed of the words while time is slowed down.
+pub const SLOW_FACTOR: f64 = 0.5;
+
+/// What happens when a special word is typed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PowerUp {
+  /// the words fall at half the speed for a while.
+  Slow,
+  /// the words stop falling for a while.
+  Freeze,
+  /// removes all words on screen.
+  Clear,
+  /// one more word may hit the ground.
+  Life,
+  /// every word gives twice the points for a while.
+  DoubleScore,
+}
+
+impl PowerUp {
+  pub const ALL: [PowerUp; 5] = [PowerUp::Slow, PowerUp::Freeze, PowerUp::Clear, PowerUp::Life, PowerUp::DoubleScore];
+
+  /// how long the effect lasts in ms, [None] for effects that happen at once.
+  pub fn duration(&self) -> Option<u128> {
+    match self {
+      PowerUp::Slow => Some(10_000),
+      PowerUp::Freeze => Some(3_000),
+      PowerUp::DoubleScore => Some(15_000),
+      PowerUp::Clear | PowerUp::Life => None,
+    }
+  }
+
+  pub fn name(&self) -> &'static str {
+    match self {
+      PowerUp::Slow => "Slow",
+      PowerUp::Freeze => "Freeze",
+      PowerUp::Clear => "Clear",
+      PowerUp::Life => "Life",
+      PowerUp::DoubleScore => "x2",
+    }
+  }
+}
+
+/// A power-up that is active until the given time in ms since the game started.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Effect {
+  pub power_up: PowerUp,
+  pub until: u128,
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::powerup::PowerUp;
+
+  #[test]
+  fn durations() {
+    let timed: Vec<PowerUp> = PowerUp::ALL.into_iter().filter(|power_up| power_up.duration().is_some()).collect();
+    assert_eq!(timed, vec![PowerUp::Slow, PowerUp::Freeze, PowerUp::DoubleScore]);
+  }
+}
@@ -0,0 +1,155 @@
+use serde::{Deserialize, Serialize};
+
+use crate::typeattack::Mode;
+
+/// number of levels in the generated progressions, the last one is kept after that.
+const GENERATED_LEVELS: usize = 100;
+
+/// How a single level plays.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Level {
+  // factor on the base speed of the game
+  pub speed: f64,
+  // the most words on screen at once
+  pub words: usize,
+  // only words with at least this many characters are spawned
+  pub min_length: usize,
+  // when set, only words with at most this many characters are spawned
+  pub max_length: Option<usize>,
+  // lives the player gets when reaching this level
+  pub lives_bonus: u16,
+}
+
+impl Default for Level {
+  fn default() -> Self {
+    Level {
+      speed: 1.0,
+      words: 1,
+      min_length: 0,
+      max_length: None,
+      lives_bonus: 0,
+    }
+  }
+}
+
+impl Level {
+  /// whether a word may be spawned on this level.
+  pub fn allows(&self, word: &str) -> bool {
+    let length = word.chars().count();
+    length >= self.min_length && self.max_length.is_none_or(|max| length <= max)
+  }
+}
+
+/// The levels of a game, starting with level 1. Levels after the end of the table play like the last one.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Progression {
+  levels: Vec<Level>,
+}
+
+impl Progression {
+  pub fn new(levels: Vec<Level>) -> Result<Self, String> {
+    let progression = Progression { levels };
+    progression.validate()?;
+    Ok(progression)
+  }
+
+  /// The progression of a mode, if no other progression is configured.
+  /// One more word is on screen every `levels_per_word` levels.
+  pub fn for_mode(mode: Mode, levels_per_word: usize, start_level: usize) -> Self {
+    let levels = (1..=GENERATED_LEVELS).map(|level| Level {
+      speed: match mode {
+        // add 1/10th of speed every level => level 10 -> double speed
+        Mode::Classic | Mode::Sprint => 1.0 + level as f64 / 10.0,
+        // zen, training and text keep the speed of the level they start at
+        Mode::Zen | Mode::Training | Mode::Text => 1.0 + start_level as f64 / 10.0,
+      },
+      words: level / levels_per_word.max(1) + 1,
+      ..Level::default()
+    }).collect();
+    Progression { levels }
+  }
+
+  /// the given level, counting from 1.
+  pub fn level(&self, level: usize) -> &Level {
+    &self.levels[level.clamp(1, self.levels.len()) - 1]
+  }
+
+  /// the lives gained by advancing from level `from` to level `to`.
+  pub fn lives_bonus(&self, from: usize, to: usize) -> u16 {
+    (from + 1..=to).map(|level| self.level(level).lives_bonus).sum()
+  }
+
+  pub fn validate(&self) -> Result<(), String> {
+    if self.levels.is_empty() {
+      return Err(String::from("The progression needs at least one level!"));
+    }
+    for (index, level) in self.levels.iter().enumerate() {
+      if !(level.speed.is_finite() && level.speed > 0.0) {
+        return Err(format!("The speed of level {} needs to be a positive number!", index + 1));
+      }
+      if level.words == 0 {
+        return Err(format!("Level {} needs at least one word on screen!", index + 1));
+      }
+      if level.max_length.is_some_and(|max| max < level.min_length.max(1)) {
+        return Err(format!("The word length range of level {} is empty!", index + 1));
+      }
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::progression::{Level, Progression};
+  use crate::typeattack::Mode;
+
+  #[test]
+  fn classic_gets_faster() {
+    let progression = Progression::for_mode(Mode::Classic, 5, 1);
+    assert!((progression.level(1).speed - 1.1).abs() < 1e-9);
+    assert!((progression.level(10).speed - 2.0).abs() < 1e-9);
+    assert_eq!(progression.level(4).words, 1);
+    assert_eq!(progression.level(5).words, 2);
+  }
+
+  #[test]
+  fn zen_keeps_the_speed() {
+    let progression = Progression::for_mode(Mode::Zen, 5, 1);
+    assert_eq!(progression.level(1).speed, progression.level(50).speed);
+    // starting at a later level keeps its speed
+    let progression = Progression::for_mode(Mode::Zen, 5, 10);
+    assert!((progression.level(10).speed - 2.0).abs() < 1e-9);
+    assert_eq!(progression.level(10).speed, progression.level(50).speed);
+  }
+
+  #[test]
+  fn last_level_is_kept() {
+    let progression = Progression::new(vec![
+      Level { words: 1, ..Level::default() },
+      Level { words: 2, lives_bonus: 1, ..Level::default() },
+    ]).unwrap();
+    assert_eq!(progression.level(0).words, 1);
+    assert_eq!(progression.level(2).words, 2);
+    assert_eq!(progression.level(7).words, 2);
+    assert_eq!(progression.lives_bonus(1, 4), 3);
+    assert_eq!(progression.lives_bonus(2, 2), 0);
+  }
+
+  #[test]
+  fn word_length() {
+    let level = Level { min_length: 3, max_length: Some(4), ..Level::default() };
+    assert!(!level.allows("ab"));
+    assert!(level.allows("für"));
+    assert!(level.allows("abcd"));
+    assert!(!level.allows("abcde"));
+  }
+
+  #[test]
+  fn invalid() {
+    assert!(Progression::new(vec![]).is_err());
+    assert!(Progression::new(vec![Level { words: 0, ..Level::default() }]).is_err());
+    assert!(Progression::new(vec![Level { speed: -1.0, ..Level::default() }]).is_err());
+    assert!(Progression::new(vec![Level { min_length: 5, max_length: Some(4), ..Level::default() }]).is_err());
+  }
+}
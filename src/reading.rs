@@ -0,0 +1,156 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use crate::storage;
+use crate::typeattack::Word;
+use crate::wordlist::WordList;
+
+/// number of words shown as one page of a text.
+pub const WORDS_PER_PAGE: usize = 250;
+
+/// How far a game got through a text. All positions are indices of words within the text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Reading {
+  // the word the game started at
+  pub start: usize,
+  // the next word to appear on screen
+  pub position: usize,
+  pub length: usize,
+}
+
+impl Reading {
+  /// the page of the next word, counting from 1.
+  pub fn page(&self) -> usize {
+    self.position.min(self.length.saturating_sub(1)) / WORDS_PER_PAGE + 1
+  }
+
+  pub fn pages(&self) -> usize {
+    self.length.div_ceil(WORDS_PER_PAGE).max(1)
+  }
+
+  /// true, if all words of the text appeared on screen.
+  pub fn is_finished(&self) -> bool {
+    self.position >= self.length
+  }
+
+  /// The position to continue the text at: the first word still on screen, or the next one.
+  /// A finished text starts over.
+  pub fn resume(&self, words: &[Word]) -> usize {
+    // the words of a text are spawned in order, so their ids count the words since the start
+    let position = words.iter()
+        .map(|word| self.start + word.id as usize - 1)
+        .min()
+        .unwrap_or(self.position);
+    match position >= self.length {
+      true => 0,
+      false => position,
+    }
+  }
+}
+
+/// Where the player stopped in each text. When created with a path, the bookmarks are stored on disk.
+pub struct Bookmarks {
+  path: Option<PathBuf>,
+  positions: BTreeMap<String, usize>,
+}
+
+impl Bookmarks {
+  /// Bookmarks that are forgotten when the game ends.
+  pub fn new() -> Self {
+    Bookmarks {
+      path: None,
+      positions: BTreeMap::new(),
+    }
+  }
+
+  /// Loads the bookmarks from the given file. A missing file has no bookmarks.
+  pub fn load(path: PathBuf) -> Result<Self, String> {
+    let positions = storage::load_json(&path, "bookmarks")?;
+    Ok(Bookmarks {
+      path: Some(path),
+      positions,
+    })
+  }
+
+  /// the bookmarks file within the data directory of the user.
+  pub fn default_path() -> Option<PathBuf> {
+    storage::data_file("bookmarks.json")
+  }
+
+  pub fn save(&self) -> Result<(), String> {
+    let path = match &self.path {
+      Some(path) => path,
+      None => return Ok(())
+    };
+    storage::save_json(path, "bookmarks", &self.positions)
+  }
+
+  /// the position the text stopped at, 0 for a new text.
+  pub fn get(&self, text: &WordList) -> usize {
+    self.positions.get(&Bookmarks::key(text)).copied().unwrap_or(0)
+  }
+
+  pub fn set(&mut self, text: &WordList, position: usize) {
+    self.positions.insert(Bookmarks::key(text), position);
+  }
+
+  /// Identifies a text by its words, so that it is found again when read from another file or stdin.
+  fn key(text: &WordList) -> String {
+    // FNV-1a, which stays the same across versions of rust
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in text.words.join(" ").bytes() {
+      hash ^= byte as u64;
+      hash = hash.wrapping_mul(0x100000001b3);
+    }
+    format!("{:016x}", hash)
+  }
+}
+
+impl Default for Bookmarks {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::reading::{Bookmarks, Reading, WORDS_PER_PAGE};
+  use crate::typeattack::Word;
+  use crate::wordlist::WordList;
+
+  #[test]
+  fn pages() {
+    let reading = Reading { start: 0, position: 0, length: 2 * WORDS_PER_PAGE + 1 };
+    assert_eq!((reading.page(), reading.pages()), (1, 3));
+    let reading = Reading { position: WORDS_PER_PAGE, ..reading };
+    assert_eq!(reading.page(), 2);
+    let reading = Reading { position: reading.length, ..reading };
+    assert_eq!(reading.page(), 3);
+    assert!(reading.is_finished());
+  }
+
+  #[test]
+  fn resume_at_the_first_word_on_screen() {
+    let reading = Reading { start: 10, position: 15, length: 20 };
+    let words = vec![Word { id: 5, ..Word::new("e", 0.0, 0.0) }, Word { id: 3, ..Word::new("c", 0.0, 0.0) }];
+    assert_eq!(reading.resume(&words), 12);
+    assert_eq!(reading.resume(&[]), 15);
+    let finished = Reading { position: 20, ..reading };
+    assert_eq!(finished.resume(&[]), 0);
+  }
+
+  #[test]
+  fn bookmarks() {
+    let path = std::env::temp_dir().join(format!("typeattack-bookmarks-{}.json", std::process::id()));
+    let text = WordList::from_text("text", "It was a dark night.").unwrap();
+    let other = WordList::from_text("text", "It was a bright day.").unwrap();
+    let mut bookmarks = Bookmarks::load(path.clone()).unwrap();
+    assert_eq!(bookmarks.get(&text), 0);
+    bookmarks.set(&text, 3);
+    bookmarks.save().unwrap();
+    let loaded = Bookmarks::load(path.clone()).unwrap();
+    std::fs::remove_file(path).unwrap();
+    assert_eq!(loaded.get(&text), 3);
+    assert_eq!(loaded.get(&other), 0);
+  }
+}
@@ -0,0 +1,174 @@
+use std::fs;
+use std::path::Path;
+use std::pin::Pin;
+use std::time::{Duration, Instant};
+
+use async_std::task::sleep;
+use futures::stream::{self, Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+
+use crate::training::Training;
+use crate::typeattack::{Event, Settings, StreamEvent};
+
+/// Version of the file format. Needs to be increased on every incompatible change.
+pub const RECORDING_VERSION: u32 = 2;
+
+/// A single entry of the game log.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Entry {
+  /// a time update, with the elapsed time in ms since the game started
+  Tick(u128),
+  /// a user input, together with the elapsed time of the last time update
+  Key(u128, Event),
+}
+
+impl Entry {
+  fn time(&self) -> u128 {
+    match self {
+      Entry::Tick(time) => *time,
+      Entry::Key(time, _) => *time,
+    }
+  }
+}
+
+/// Everything needed to replay a game: the seed, the settings and all
+/// time updates and key events in the order the game received them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Recording {
+  pub version: u32,
+  pub seed: u64,
+  pub settings: Settings,
+  // the statistics a training game started with, as they decide which words come
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub training: Option<Training>,
+  pub entries: Vec<Entry>,
+}
+
+impl Recording {
+  pub fn new(seed: u64, settings: Settings, entries: Vec<Entry>) -> Self {
+    Recording {
+      version: RECORDING_VERSION,
+      seed,
+      settings,
+      training: None,
+      entries,
+    }
+  }
+
+  pub fn load(path: &Path) -> Result<Self, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|error| format!("Could not read recording {}: {}", path.display(), error))?;
+    let recording: Recording = serde_json::from_str(&content)
+        .map_err(|error| format!("Could not parse recording {}: {}", path.display(), error))?;
+    if recording.version != RECORDING_VERSION {
+      return Err(format!(
+        "Recording {} has version {}, but only version {} is supported!",
+        path.display(), recording.version, RECORDING_VERSION
+      ));
+    }
+    Ok(recording)
+  }
+
+  pub fn save(&self, path: &Path) -> Result<(), String> {
+    let content = serde_json::to_string(self)
+        .map_err(|error| format!("Could not serialize recording: {}", error))?;
+    fs::write(path, content)
+        .map_err(|error| format!("Could not write recording {}: {}", path.display(), error))
+  }
+
+  /// Emits all entries at the time they were recorded.
+  pub(crate) fn stream(&self) -> Pin<Box<dyn Stream<Item=StreamEvent>>> {
+    let start = Instant::now();
+    stream::iter(self.entries.clone())
+        .then(move |entry| async move {
+          let elapsed = start.elapsed().as_millis();
+          if entry.time() > elapsed {
+            sleep(Duration::from_millis((entry.time() - elapsed) as u64)).await;
+          }
+          match entry {
+            Entry::Tick(time) => StreamEvent::TimeUpdate(time),
+            Entry::Key(_, event) => StreamEvent::KeyEvent(event),
+          }
+        })
+        .boxed_local()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::env::temp_dir;
+  use std::fs;
+
+  use crate::clock::{StepClock, SystemClock};
+  use crate::headless::{Frame, Headless};
+  use crate::recording::{Entry, Recording, RECORDING_VERSION};
+  use crate::training::Training;
+  use crate::typeattack::{Event, Keystroke, Mode, Settings, Typeattack, WorldState};
+
+  #[test]
+  fn save_and_load() {
+    let path = temp_dir().join("typeattack-save-and-load.json");
+    let recording = Recording::new(7, Settings::default(), vec![
+      Entry::Tick(16),
+      Entry::Key(16, Event::AddChar('a')),
+      Entry::Key(16, Event::Stop),
+    ]);
+    recording.save(&path).unwrap();
+    assert_eq!(Recording::load(&path).unwrap(), recording);
+  }
+
+  #[test]
+  fn unknown_version_is_rejected() {
+    let path = temp_dir().join("typeattack-unknown-version.json");
+    let mut recording = Recording::new(7, Settings::default(), vec![]);
+    recording.version = RECORDING_VERSION + 1;
+    fs::write(&path, serde_json::to_string(&recording).unwrap()).unwrap();
+    assert!(Recording::load(&path).is_err());
+  }
+
+  /// the frames of the recorded game without the menu, and the frames of its replay.
+  fn record_and_replay(game: &mut Typeattack, engine: &Headless, path: &str) -> (Vec<Frame>, Vec<Frame>) {
+    let path = temp_dir().join(path);
+    game.record_to(path.clone());
+    game.start();
+
+    let recording = Recording::load(&path).unwrap();
+    let replay_engine = Headless::new(vec![]);
+    Typeattack::new_with_settings(
+      Box::new(replay_engine.clone()),
+      recording.settings.clone(),
+      Some(recording.seed),
+      Box::new(SystemClock::new()),
+    ).replay(&recording);
+
+    // the menu isn't part of a replay
+    let frames: Vec<Frame> = engine.frames().into_iter().filter(|frame| *frame != Frame::Menu).collect();
+    (frames, replay_engine.frames())
+  }
+
+  #[test]
+  fn replay_renders_identically() {
+    let events = vec![Event::AddChar('s'), Event::AddChar('a'), Event::RemoveChar, Event::AddChar('b'), Event::Stop];
+    let engine = Headless::new(events);
+    let mut game = Typeattack::new_with_seed(Box::new(engine.clone()), 3, Box::new(StepClock::new(16)));
+    let (frames, replayed) = record_and_replay(&mut game, &engine, "typeattack-replay.json");
+    assert_eq!(replayed, frames);
+  }
+
+  #[test]
+  fn training_replays_with_its_statistics() {
+    let mut weak = WorldState::new();
+    weak.keystrokes = vec![Keystroke { expected: 'q', previous: None, correct: false }; 10];
+    let mut training = Training::new();
+    training.add(&weak);
+    let mut events = vec![Event::AddChar('s')];
+    events.extend(std::iter::repeat_n(Event::RemoveChar, 40));
+    events.push(Event::Stop);
+    let engine = Headless::new(events);
+    let settings = Settings { mode: Mode::Training, speed: 0.01, spawning: None, ..Settings::default() };
+    let mut game = Typeattack::new_with_settings(Box::new(engine.clone()), settings, Some(3), Box::new(StepClock::new(16)));
+    game.use_training(training);
+    let (frames, replayed) = record_and_replay(&mut game, &engine, "typeattack-replay-training.json");
+    assert_eq!(replayed, frames);
+  }
+}
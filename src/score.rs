@@ -0,0 +1,42 @@
+/// points for every character of a typed word.
+const POINTS_PER_CHAR: f64 = 10.0;
+/// the multiplier grows by one every this many words in a combo.
+const WORDS_PER_MULTIPLIER: u32 = 5;
+const MAX_MULTIPLIER: u32 = 8;
+/// a boss gives this many times the points of a word of the same length.
+pub const BOSS_FACTOR: u64 = 3;
+/// points lost for every word hitting the ground.
+pub const MISS_PENALTY: u64 = 50;
+
+/// The base points of a word typed at the height `y`: longer words give more points,
+/// and a word typed right after it appeared counts twice as much as one about to hit the ground.
+pub fn word_points(word: &str, y: f64) -> u64 {
+  let height = (1.0 - y).clamp(0.0, 1.0);
+  (word.chars().count() as f64 * POINTS_PER_CHAR * (1.0 + height)).round() as u64
+}
+
+/// the multiplier of the next word, after `combo` words typed without a mistake.
+pub fn multiplier(combo: u32) -> u32 {
+  (1 + combo / WORDS_PER_MULTIPLIER).min(MAX_MULTIPLIER)
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::score::{multiplier, word_points, MAX_MULTIPLIER};
+
+  #[test]
+  fn points() {
+    assert_eq!(word_points("test", 0.0), 80);
+    assert_eq!(word_points("test", 0.5), 60);
+    assert_eq!(word_points("test", 1.0), 40);
+    assert_eq!(word_points("größe", 1.0), 50);
+  }
+
+  #[test]
+  fn combo() {
+    assert_eq!(multiplier(0), 1);
+    assert_eq!(multiplier(4), 1);
+    assert_eq!(multiplier(5), 2);
+    assert_eq!(multiplier(1000), MAX_MULTIPLIER);
+  }
+}
@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+
+use crate::wordlist::WordList;
+
+/// How the next word is picked from the words a level allows.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Selection {
+  // how much more often used words come up: 0 picks every word equally often, 1 follows their rank
+  pub frequency: f64,
+  // a word doesn't come again within this many words
+  pub recent: usize,
+  // words on screen don't start with the same this many characters, 0 allows any
+  pub prefix: usize,
+}
+
+impl Default for Selection {
+  fn default() -> Self {
+    Selection {
+      frequency: 0.5,
+      recent: 20,
+      prefix: 1,
+    }
+  }
+}
+
+impl Selection {
+  pub fn validate(&self) -> Result<(), String> {
+    if !(self.frequency.is_finite() && self.frequency >= 0.0) {
+      return Err(format!("The frequency of the selection needs to be at least 0, but is {}!", self.frequency));
+    }
+    Ok(())
+  }
+
+  /// The weights of the candidates for the next word. Words that came up recently or share a prefix
+  /// with a word on screen are left out, unless no word would be left.
+  pub fn weights(&self, list: &WordList, candidates: &[&String], recent: &[String], on_screen: &[&str]) -> Vec<f64> {
+    // words without a rank are less used than all ranked ones
+    let highest = list.ranks.values().max().map_or(0, |rank| *rank as usize);
+    let unranked = (highest + 1).max(list.words.len()) as f64;
+    let weights: Vec<f64> = candidates.iter()
+        .map(|word| list.rank(word).map_or(unranked, |rank| rank as f64).powf(-self.frequency))
+        .collect();
+    let recent = &recent[recent.len().saturating_sub(self.recent)..];
+    let filtered: Vec<f64> = candidates.iter().zip(&weights)
+        .map(|(word, weight)| {
+          let repeated = recent.contains(word);
+          let shares_prefix = self.prefix > 0 && on_screen.iter().any(|other| self.start_of(word) == self.start_of(other));
+          if repeated || shares_prefix { 0.0 } else { *weight }
+        })
+        .collect();
+    match filtered.iter().any(|weight| *weight > 0.0) {
+      true => filtered,
+      false => weights,
+    }
+  }
+
+  fn start_of(&self, word: &str) -> String {
+    word.chars().flat_map(char::to_lowercase).take(self.prefix).collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::selection::Selection;
+  use crate::wordlist::{WordFormat, WordList};
+
+  fn list() -> WordList {
+    WordList::parse("list", r#"[{"word": "the", "rank": 1}, {"word": "of", "rank": 4}, "task"]"#, WordFormat::Json).unwrap()
+  }
+
+  fn candidates(list: &WordList) -> Vec<&String> {
+    list.words.iter().collect()
+  }
+
+  #[test]
+  fn used_words_come_up_more_often() {
+    let list = list();
+    let selection = Selection { frequency: 1.0, recent: 0, prefix: 0 };
+    // the unranked "task" comes after the rank 4 of "of"
+    assert_eq!(selection.weights(&list, &candidates(&list), &[], &[]), vec![1.0, 0.25, 0.2]);
+    let uniform = Selection { frequency: 0.0, ..selection };
+    assert_eq!(uniform.weights(&list, &candidates(&list), &[], &[]), vec![1.0; 3]);
+  }
+
+  #[test]
+  fn recent_words_and_prefixes_are_avoided() {
+    let list = list();
+    let selection = Selection { frequency: 0.0, recent: 1, prefix: 1 };
+    let recent = [String::from("of"), String::from("the")];
+    assert_eq!(selection.weights(&list, &candidates(&list), &recent, &[]), vec![0.0, 1.0, 1.0]);
+    assert_eq!(selection.weights(&list, &candidates(&list), &recent, &["Tea"]), vec![0.0, 1.0, 0.0]);
+    // rather a repeated word than none at all
+    assert_eq!(selection.weights(&list, &candidates(&list), &recent, &["tea", "off"]), vec![1.0; 3]);
+  }
+
+  #[test]
+  fn invalid() {
+    assert!(Selection { frequency: -1.0, ..Selection::default() }.validate().is_err());
+    assert!(Selection::default().validate().is_ok());
+  }
+}
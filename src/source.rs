@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// file extensions of the source files the words are taken from.
+const EXTENSIONS: &[&str] = &[
+  "rs", "py", "go", "js", "jsx", "ts", "tsx", "sh", "bash", "zsh", "c", "h", "cpp", "hpp", "cs", "java", "kt",
+  "swift", "rb", "php", "lua",
+];
+/// directories with generated or third party code, hidden directories are skipped as well.
+const IGNORED_DIRS: &[&str] = &["target", "node_modules", "vendor", "build", "dist", "__pycache__"];
+/// the symbols taken from the sources, longer ones before the ones they start with.
+const SYMBOLS: &[&str] = &[
+  "...", "===", "!==", "..=", "->", "=>", "::", "&&", "||", "==", "!=", "<=", ">=", ":=", "+=", "-=", "<-", "??",
+  "?.", "{}", "()", "[]",
+];
+const MIN_IDENTIFIER_LENGTH: usize = 2;
+const MAX_IDENTIFIER_LENGTH: usize = 30;
+/// maximum number of words taken from the sources.
+pub const MAX_SOURCE_WORDS: usize = 1000;
+
+/// The identifiers and symbols used in the source files within the directory and its
+/// subdirectories, the most used ones first.
+pub fn words(dir: &Path) -> Result<Vec<String>, String> {
+  let mut counts: HashMap<String, usize> = HashMap::new();
+  visit(dir, &mut counts)?;
+  let mut words: Vec<(String, usize)> = counts.into_iter().collect();
+  words.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then_with(|| a.cmp(b)));
+  Ok(words.into_iter().take(MAX_SOURCE_WORDS).map(|(word, _)| word).collect())
+}
+
+/// counts the words of all source files within the directory, in a stable order.
+/// Only an error reading the directory itself is returned, unreadable subdirectories are skipped.
+fn visit(dir: &Path, counts: &mut HashMap<String, usize>) -> Result<(), String> {
+  let entries = fs::read_dir(dir)
+      .map_err(|error| format!("Could not read {}: {}", dir.display(), error))?;
+  // the file type doesn't follow symlinks, so linked directories are skipped and can't lead in a circle
+  let mut paths: Vec<_> = entries.filter_map(Result::ok)
+      .filter_map(|entry| entry.file_type().ok().map(|file_type| (entry.path(), file_type)))
+      .collect();
+  paths.sort_by(|(a, _), (b, _)| a.cmp(b));
+  for (path, file_type) in paths {
+    let name = path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+    if name.starts_with('.') {
+      continue;
+    }
+    if file_type.is_dir() {
+      if !IGNORED_DIRS.contains(&name.as_str()) {
+        // like files that can't be read, directories that can't be read have no sources
+        let _ = visit(&path, counts);
+      }
+    } else if path.extension().and_then(|extension| extension.to_str()).is_some_and(|extension| EXTENSIONS.contains(&extension)) {
+      // files that aren't text are no sources
+      if let Ok(content) = fs::read_to_string(&path) {
+        for token in tokens(&content) {
+          *counts.entry(String::from(token)).or_default() += 1;
+        }
+      }
+    }
+  }
+  Ok(())
+}
+
+/// The identifiers and symbols of a source text. Numbers, strings and single characters
+/// are split up as well, but aren't part of the result.
+pub fn tokens(text: &str) -> Vec<&str> {
+  let mut tokens = Vec::new();
+  let mut index = 0;
+  while let Some(c) = text[index..].chars().next() {
+    let rest = &text[index..];
+    if c.is_alphanumeric() || c == '_' {
+      let end = rest.find(|c: char| !(c.is_alphanumeric() || c == '_')).unwrap_or(rest.len());
+      let token = &rest[..end];
+      let length = token.chars().count();
+      if !c.is_numeric() && (MIN_IDENTIFIER_LENGTH..=MAX_IDENTIFIER_LENGTH).contains(&length) {
+        tokens.push(token);
+      }
+      index += end;
+    } else if let Some(symbol) = SYMBOLS.iter().find(|symbol| rest.starts_with(**symbol)) {
+      tokens.push(&rest[..symbol.len()]);
+      index += symbol.len();
+    } else {
+      index += c.len_utf8();
+    }
+  }
+  tokens
+}
+
+#[cfg(test)]
+mod tests {
+  use std::fs;
+
+  use crate::source::{tokens, words};
+
+  #[test]
+  fn identifiers_and_symbols() {
+    assert_eq!(
+      tokens("fn main() -> Result<(), String> { let x_1 = a::b && 42; }"),
+      vec!["fn", "main", "()", "->", "Result", "()", "String", "let", "x_1", "::", "&&"]
+    );
+    assert_eq!(tokens("if a !== b ... c"), vec!["if", "!==", "..."]);
+    assert_eq!(tokens("größe = 0x1F"), vec!["größe"]);
+  }
+
+  #[test]
+  fn most_used_first() {
+    let dir = std::env::temp_dir().join(format!("typeattack-source-{}", std::process::id()));
+    fs::create_dir_all(dir.join("src")).unwrap();
+    fs::create_dir_all(dir.join("target")).unwrap();
+    fs::write(dir.join("src").join("main.rs"), "fn main() { run(); run(); }").unwrap();
+    fs::write(dir.join("script.py"), "def run():\n  pass").unwrap();
+    fs::write(dir.join("target").join("generated.rs"), "fn generated() {}").unwrap();
+    fs::write(dir.join("notes.md"), "some notes").unwrap();
+    let words = words(&dir).unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(words, vec!["()", "run", "def", "fn", "main", "pass"]);
+  }
+
+  #[test]
+  fn missing_directory() {
+    assert!(words(&std::env::temp_dir().join("typeattack-source-missing")).is_err());
+  }
+
+  #[cfg(unix)]
+  #[test]
+  fn linked_directories_are_skipped() {
+    let dir = std::env::temp_dir().join(format!("typeattack-source-link-{}", std::process::id()));
+    fs::create_dir_all(dir.join("src")).unwrap();
+    fs::write(dir.join("src").join("lib.rs"), "pub fn run() {}").unwrap();
+    std::os::unix::fs::symlink("..", dir.join("src").join("up")).unwrap();
+    let words = words(&dir);
+    fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(words.unwrap(), vec!["()", "fn", "pub", "run", "{}"]);
+  }
+}
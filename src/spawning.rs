@@ -0,0 +1,168 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+/// When new words come onto the screen and how fast each of them falls.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Spawning {
+  // ms between two new words at a level speed of 1.0, faster levels bring their words sooner
+  pub interval: u128,
+  // how much an interval varies at random, as a fraction of it
+  pub jitter: f64,
+  // chance of several words coming at once
+  pub burst: f64,
+  // the most words coming at once in a burst
+  pub burst_words: usize,
+  // how much the speed of a word varies at random, as a fraction of the speed of the level
+  pub speed_spread: f64,
+  // words in a wave, which is followed by a break without new words; 0 for no waves
+  pub wave_words: usize,
+  // ms of the break after a wave
+  pub wave_break: u128,
+}
+
+impl Default for Spawning {
+  fn default() -> Self {
+    Spawning {
+      interval: 1500,
+      jitter: 0.3,
+      burst: 0.1,
+      burst_words: 3,
+      speed_spread: 0.2,
+      wave_words: 30,
+      wave_break: 3000,
+    }
+  }
+}
+
+/// When the next words come, kept with the state of a game.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Schedule {
+  // time in ms the next words may come at
+  pub next: u128,
+  // words that came in the current wave
+  pub wave: usize,
+}
+
+impl Spawning {
+  pub fn validate(&self) -> Result<(), String> {
+    if !(0.0..=1.0).contains(&self.jitter) {
+      return Err(format!("The jitter of the spawning needs to be within 0 and 1, but is {}!", self.jitter));
+    }
+    if !(0.0..=1.0).contains(&self.burst) {
+      return Err(format!("The burst chance needs to be within 0 and 1, but is {}!", self.burst));
+    }
+    if self.burst_words < 2 {
+      return Err(String::from("A burst needs at least 2 words!"));
+    }
+    if !(0.0..1.0).contains(&self.speed_spread) {
+      return Err(format!("The speed spread needs to be at least 0 and below 1, but is {}!", self.speed_spread));
+    }
+    Ok(())
+  }
+
+  /// The number of words coming at `time`, at most the `missing` ones. 0 while the schedule waits.
+  pub fn due(&self, schedule: &Schedule, time: u128, missing: usize, random: &mut impl Rng) -> usize {
+    if missing == 0 || time < schedule.next {
+      return 0;
+    }
+    let count = match self.burst > 0.0 && random.gen_bool(self.burst) {
+      true => random.gen_range(2..=self.burst_words),
+      false => 1,
+    };
+    count.min(missing)
+  }
+
+  /// The schedule after `count` words came at `time`. `pace` is the factor on the speed of the level.
+  pub fn after(&self, schedule: &Schedule, count: usize, time: u128, pace: f64, random: &mut impl Rng) -> Schedule {
+    let wave = schedule.wave + count;
+    if self.wave_words > 0 && wave >= self.wave_words {
+      return Schedule { next: time + self.wave_break, wave: 0 };
+    }
+    let jitter = match self.jitter > 0.0 {
+      true => random.gen_range(-self.jitter..=self.jitter),
+      false => 0.0,
+    };
+    let interval = self.interval as f64 * (1.0 + jitter) / pace;
+    Schedule { next: time + interval.round() as u128, wave }
+  }
+
+  /// a random factor on the speed of a new word.
+  pub fn speed(&self, random: &mut impl Rng) -> f64 {
+    match self.speed_spread > 0.0 {
+      true => 1.0 + random.gen_range(-self.speed_spread..=self.speed_spread),
+      false => 1.0,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use rand::rngs::StdRng;
+  use rand::SeedableRng;
+
+  use crate::spawning::{Schedule, Spawning};
+
+  fn steady() -> Spawning {
+    Spawning { jitter: 0.0, burst: 0.0, speed_spread: 0.0, wave_words: 0, ..Spawning::default() }
+  }
+
+  #[test]
+  fn words_wait_for_the_interval() {
+    let mut random = StdRng::seed_from_u64(1);
+    let spawning = steady();
+    let schedule = Schedule { next: 1000, wave: 0 };
+    assert_eq!(spawning.due(&schedule, 999, 3, &mut random), 0);
+    assert_eq!(spawning.due(&schedule, 1000, 3, &mut random), 1);
+    assert_eq!(spawning.due(&schedule, 1000, 0, &mut random), 0);
+    assert_eq!(spawning.after(&schedule, 1, 1000, 1.0, &mut random), Schedule { next: 2500, wave: 1 });
+    // faster levels bring their words sooner
+    assert_eq!(spawning.after(&schedule, 1, 1000, 2.0, &mut random).next, 1750);
+  }
+
+  #[test]
+  fn intervals_vary() {
+    let mut random = StdRng::seed_from_u64(1);
+    let spawning = Spawning { jitter: 0.5, ..steady() };
+    let nexts: Vec<u128> = (0..20).map(|_| spawning.after(&Schedule::default(), 1, 0, 1.0, &mut random).next).collect();
+    assert!(nexts.iter().all(|next| (750..=2250).contains(next)));
+    assert!(nexts.iter().any(|next| *next != nexts[0]));
+  }
+
+  #[test]
+  fn bursts_bring_several_words() {
+    let mut random = StdRng::seed_from_u64(1);
+    let spawning = Spawning { burst: 1.0, burst_words: 4, ..steady() };
+    let counts: Vec<usize> = (0..20).map(|_| spawning.due(&Schedule::default(), 0, 10, &mut random)).collect();
+    assert!(counts.iter().all(|count| (2..=4).contains(count)));
+    assert_eq!(spawning.due(&Schedule::default(), 0, 1, &mut random), 1);
+  }
+
+  #[test]
+  fn waves_end_with_a_break() {
+    let mut random = StdRng::seed_from_u64(1);
+    let spawning = Spawning { wave_words: 3, wave_break: 5000, ..steady() };
+    let schedule = spawning.after(&Schedule::default(), 2, 0, 1.0, &mut random);
+    assert_eq!(schedule, Schedule { next: 1500, wave: 2 });
+    assert_eq!(spawning.after(&schedule, 1, 1500, 1.0, &mut random), Schedule { next: 6500, wave: 0 });
+  }
+
+  #[test]
+  fn speeds_vary() {
+    let mut random = StdRng::seed_from_u64(1);
+    let spawning = Spawning { speed_spread: 0.2, ..steady() };
+    let speeds: Vec<f64> = (0..20).map(|_| spawning.speed(&mut random)).collect();
+    assert!(speeds.iter().all(|speed| (0.8..=1.2).contains(speed)));
+    assert!(speeds.iter().any(|speed| *speed != 1.0));
+    assert_eq!(steady().speed(&mut random), 1.0);
+  }
+
+  #[test]
+  fn invalid() {
+    assert!(Spawning { jitter: 1.5, ..Spawning::default() }.validate().is_err());
+    assert!(Spawning { burst: -0.1, ..Spawning::default() }.validate().is_err());
+    assert!(Spawning { burst_words: 1, ..Spawning::default() }.validate().is_err());
+    assert!(Spawning { speed_spread: 1.0, ..Spawning::default() }.validate().is_err());
+    assert!(Spawning::default().validate().is_ok());
+  }
+}
@@ -0,0 +1,137 @@
+use crate::adaptive::Decision;
+use crate::typeattack::WorldState;
+
+/// by convention, a word is 5 characters long when calculating WPM.
+pub(crate) const CHARS_PER_WORD: f64 = 5.0;
+pub(crate) const MS_PER_MINUTE: f64 = 60_000.0;
+
+/// Typing statistics of a finished game.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statistics {
+  // duration of the game in ms
+  pub duration: u128,
+  // words per minute, counting every typed character
+  pub gross_wpm: f64,
+  // words per minute, with every rejected character as a penalty
+  pub net_wpm: f64,
+  // correctly typed characters per minute
+  pub cpm: f64,
+  // ratio of accepted characters to all typed characters, between 0.0 and 1.0
+  pub accuracy: f64,
+  // average time from the first key to the completion of a word in ms
+  pub time_per_word: Option<f64>,
+  // average time from the appearance of a word to the first key in ms
+  pub reaction_time: Option<f64>,
+  // the final adjustment of the adaptive difficulty, if it was used
+  pub difficulty: Option<i32>,
+  // every change of the adaptive difficulty
+  pub adjustments: Vec<Decision>,
+}
+
+impl Statistics {
+  pub fn new(state: &WorldState) -> Self {
+    let typed = state.accepted + state.rejected;
+    let minutes = state.time as f64 / MS_PER_MINUTE;
+    let per_minute = |value: f64| if minutes > 0.0 { value / minutes } else { 0.0 };
+    let gross_wpm = per_minute(typed as f64 / CHARS_PER_WORD);
+    let net_wpm = (gross_wpm - per_minute(state.rejected as f64)).max(0.0);
+    let accuracy = if typed > 0 { state.accepted as f64 / typed as f64 } else { 1.0 };
+    Statistics {
+      duration: state.time,
+      gross_wpm,
+      net_wpm,
+      cpm: per_minute(state.accepted as f64),
+      accuracy,
+      time_per_word: average(state.completed.iter().map(|word| word.finished - word.first_key)),
+      reaction_time: average(state.completed.iter().map(|word| word.first_key.saturating_sub(word.spawned))),
+      difficulty: state.difficulty.as_ref().map(|difficulty| difficulty.step),
+      adjustments: state.difficulty.as_ref().map(|difficulty| difficulty.decisions.clone()).unwrap_or_default(),
+    }
+  }
+
+  /// the number of times the adaptive difficulty made the game harder.
+  pub fn harder_adjustments(&self) -> usize {
+    // every adjustment is a single step, starting from 0
+    let steps = std::iter::once(0).chain(self.adjustments.iter().map(|decision| decision.step));
+    steps.clone().zip(steps.skip(1)).filter(|(before, after)| after > before).count()
+  }
+}
+
+fn average(values: impl Iterator<Item=u128>) -> Option<f64> {
+  let (sum, count) = values.fold((0, 0), |(sum, count), value| (sum + value, count + 1));
+  match count {
+    0 => None,
+    _ => Some(sum as f64 / count as f64)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::adaptive::{Adaptive, Difficulty, Progress};
+  use crate::statistics::Statistics;
+  use crate::typeattack::{CompletedWord, WorldState};
+
+  fn completed(word: &str, spawned: u128, first_key: u128, finished: u128) -> CompletedWord {
+    CompletedWord { word: String::from(word), spawned, first_key, finished }
+  }
+
+  #[test]
+  fn empty_game() {
+    let statistics = Statistics::new(&WorldState::new());
+    assert_eq!(statistics.gross_wpm, 0.0);
+    assert_eq!(statistics.net_wpm, 0.0);
+    assert_eq!(statistics.cpm, 0.0);
+    assert_eq!(statistics.accuracy, 1.0);
+    assert_eq!(statistics.time_per_word, None);
+    assert_eq!(statistics.reaction_time, None);
+  }
+
+  #[test]
+  fn speed_and_accuracy() {
+    let mut state = WorldState::new();
+    state.time = 60_000;
+    state.accepted = 45;
+    state.rejected = 5;
+    let statistics = Statistics::new(&state);
+    assert_eq!(statistics.gross_wpm, 10.0);
+    assert_eq!(statistics.net_wpm, 5.0);
+    assert_eq!(statistics.cpm, 45.0);
+    assert_eq!(statistics.accuracy, 0.9);
+  }
+
+  #[test]
+  fn net_wpm_is_never_negative() {
+    let mut state = WorldState::new();
+    state.time = 60_000;
+    state.rejected = 10;
+    assert_eq!(Statistics::new(&state).net_wpm, 0.0);
+  }
+
+  #[test]
+  fn word_timing() {
+    let mut state = WorldState::new();
+    state.time = 10_000;
+    state.completed = vec![
+      completed("test", 0, 1000, 2000),
+      completed("word", 2000, 2500, 5500),
+    ];
+    let statistics = Statistics::new(&state);
+    assert_eq!(statistics.time_per_word, Some(2000.0));
+    assert_eq!(statistics.reaction_time, Some(750.0));
+  }
+
+  #[test]
+  fn adaptive_difficulty() {
+    assert_eq!(Statistics::new(&WorldState::new()).difficulty, None);
+    let mut state = WorldState::new();
+    let mut difficulty = Difficulty::default();
+    for (time, words, fails) in [(10_000, 10, 0), (20_000, 20, 0), (30_000, 20, 5)] {
+      difficulty.evaluate(&Adaptive::default(), Progress { time, words, fails, accepted: words * 5 }, 0.0);
+    }
+    state.difficulty = Some(difficulty);
+    let statistics = Statistics::new(&state);
+    assert_eq!(statistics.difficulty, Some(1));
+    assert_eq!(statistics.adjustments.len(), 3);
+    assert_eq!(statistics.harder_adjustments(), 2);
+  }
+}
@@ -0,0 +1,33 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// the file with the given name within the data directory of the user.
+pub fn data_file(name: &str) -> Option<PathBuf> {
+  dirs::data_dir().map(|dir| dir.join("typeattack").join(name))
+}
+
+/// Reads the JSON file at `path`, or the default data if there is no such file yet.
+/// `what` names the data in the error messages.
+pub fn load_json<T: DeserializeOwned + Default>(path: &Path, what: &str) -> Result<T, String> {
+  match fs::read_to_string(path) {
+    Ok(content) => serde_json::from_str(&content)
+        .map_err(|error| format!("Could not parse {} {}: {}", what, path.display(), error)),
+    Err(_) if !path.exists() => Ok(T::default()),
+    Err(error) => Err(format!("Could not read {} {}: {}", what, path.display(), error)),
+  }
+}
+
+/// Writes the data as JSON to `path`, creating the directory if needed.
+pub fn save_json<T: Serialize>(path: &Path, what: &str, data: &T) -> Result<(), String> {
+  if let Some(dir) = path.parent() {
+    fs::create_dir_all(dir)
+        .map_err(|error| format!("Could not create {}: {}", dir.display(), error))?;
+  }
+  let content = serde_json::to_string_pretty(data)
+      .map_err(|error| format!("Could not serialize {}: {}", what, error))?;
+  fs::write(path, content)
+      .map_err(|error| format!("Could not write {} {}: {}", what, path.display(), error))
+}
@@ -0,0 +1,172 @@
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Brings a text into its composed form, so that "é" typed as "e" + "´" equals "é".
+pub fn normalize(text: &str) -> String {
+  text.nfc().collect()
+}
+
+/// true, if the word starts with the typed text. The comparison is done on the
+/// decomposed forms, so that a partially typed character ("e" of "é") matches as well.
+pub fn is_prefix(word: &str, typed: &str) -> bool {
+  let mut word = word.nfd();
+  typed.nfd().all(|c| word.next() == Some(c))
+}
+
+/// true, if the typed text is the word.
+pub fn is_complete(word: &str, typed: &str) -> bool {
+  word.nfd().eq(typed.nfd())
+}
+
+/// the character of the word following the typed text, if the word starts with it.
+pub fn next_char(word: &str, typed: &str) -> Option<char> {
+  let typed = normalize(typed);
+  match is_prefix(word, &typed) {
+    true => normalize(word).chars().nth(typed.chars().count()),
+    false => None,
+  }
+}
+
+/// number of terminal columns the text needs.
+pub fn width(text: &str) -> usize {
+  text.width()
+}
+
+/// Splits the text at spaces into lines of at most `width` columns, each with the byte index it starts at.
+/// The spaces at the line breaks are dropped and words wider than a line are cut off.
+pub fn wrap(text: &str, width: usize) -> Vec<(usize, &str)> {
+  let mut lines = Vec::new();
+  let mut start = 0;
+  let mut end = 0;
+  let mut offset = 0;
+  for word in text.split(' ') {
+    let word_end = offset + word.len();
+    if end > start && self::width(&text[start..word_end]) > width {
+      lines.push((start, &text[start..end]));
+      start = offset;
+    }
+    end = word_end;
+    offset = word_end + 1;
+  }
+  lines.push((start, &text[start..end]));
+  lines.into_iter().map(|(start, line)| (start, truncate(line, width))).collect()
+}
+
+/// The width and height of the wrapped text on a screen with the given number of columns and rows,
+/// as fractions of the screen.
+pub fn block_size(text: &str, columns: u16, rows: u16) -> (f64, f64) {
+  let lines = wrap(text, columns as usize);
+  let width = lines.iter().map(|(_, line)| self::width(line)).max().unwrap_or(0);
+  (width as f64 / columns.max(1) as f64, lines.len() as f64 / rows.max(1) as f64)
+}
+
+/// the beginning of the text that fits into `width` columns.
+pub fn truncate(text: &str, width: usize) -> &str {
+  let mut used = 0;
+  for (index, grapheme) in text.grapheme_indices(true) {
+    used += grapheme.width();
+    if used > width {
+      return &text[..index];
+    }
+  }
+  text
+}
+
+/// the first characters of the word, as many as the user sees in the typed text.
+pub fn typed_part<'a>(word: &'a str, typed: &str) -> &'a str {
+  let count = typed.graphemes(true).count();
+  let end = word.grapheme_indices(true).nth(count).map(|(index, _)| index).unwrap_or(word.len());
+  &word[..end]
+}
+
+/// removes the last character the user sees, which might consist of multiple chars.
+pub fn pop(text: &mut String) {
+  if let Some((index, _)) = text.grapheme_indices(true).next_back() {
+    text.truncate(index);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::text::{block_size, is_complete, is_prefix, next_char, normalize, pop, truncate, typed_part, width, wrap};
+
+  #[test]
+  fn prefix() {
+    assert!(is_prefix("über", ""));
+    assert!(is_prefix("über", "ü"));
+    assert!(is_prefix("über", "u"));
+    assert!(is_prefix("über", "u\u{308}b"));
+    assert!(!is_prefix("über", "ub"));
+    assert!(!is_prefix("über", "übers"));
+  }
+
+  #[test]
+  fn complete() {
+    assert!(is_complete("café", "café"));
+    assert!(is_complete("café", "cafe\u{301}"));
+    assert!(!is_complete("café", "cafe"));
+  }
+
+  #[test]
+  fn next() {
+    assert_eq!(next_char("über", ""), Some('ü'));
+    assert_eq!(next_char("über", "üb"), Some('e'));
+    assert_eq!(next_char("über", "über"), None);
+    assert_eq!(next_char("über", "x"), None);
+  }
+
+  #[test]
+  fn normalized() {
+    assert_eq!(normalize("cafe\u{301}"), "café");
+  }
+
+  #[test]
+  fn display_width() {
+    assert_eq!(width("TEST"), 4);
+    assert_eq!(width("größe"), 5);
+    assert_eq!(width("cafe\u{301}"), 4);
+    assert_eq!(width("日本"), 4);
+  }
+
+  #[test]
+  fn wrapped() {
+    assert_eq!(wrap("one two three", 80), vec![(0, "one two three")]);
+    assert_eq!(wrap("one two three", 7), vec![(0, "one two"), (8, "three")]);
+    assert_eq!(wrap("one two three", 6), vec![(0, "one"), (4, "two"), (8, "three")]);
+    assert_eq!(wrap("verylongword end", 4), vec![(0, "very"), (13, "end")]);
+    assert_eq!(wrap("", 4), vec![(0, "")]);
+  }
+
+  #[test]
+  fn block() {
+    assert_eq!(block_size("test", 80, 20), (0.05, 0.05));
+    assert_eq!(block_size("one two three", 8, 20), (0.875, 0.1));
+  }
+
+  #[test]
+  fn truncated() {
+    assert_eq!(truncate("größe", 3), "grö");
+    assert_eq!(truncate("日本", 3), "日");
+    assert_eq!(truncate("test", 5), "test");
+  }
+
+  #[test]
+  fn typed() {
+    assert_eq!(typed_part("żółw", "żó"), "żó");
+    assert_eq!(typed_part("żółw", "zo\u{301}"), "żó");
+    assert_eq!(typed_part("żółw", "żółwie"), "żółw");
+  }
+
+  #[test]
+  fn pop_grapheme() {
+    let mut text = String::from("cafe\u{301}");
+    pop(&mut text);
+    assert_eq!(text, "caf");
+    pop(&mut text);
+    assert_eq!(text, "ca");
+    let mut empty = String::new();
+    pop(&mut empty);
+    assert_eq!(empty, "");
+  }
+}
@@ -0,0 +1,290 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::storage;
+use crate::typeattack::WorldState;
+
+/// keys and bigrams need this many attempts before their error rate counts.
+const MIN_ATTEMPTS: u64 = 5;
+/// number of keys, bigrams and words shown as the weakest ones.
+pub const WEAKEST_SIZE: usize = 5;
+/// how much the error rates of its keys and bigrams raise the chance of a word in training.
+const KEY_WEIGHT: f64 = 20.0;
+const BIGRAM_WEIGHT: f64 = 20.0;
+/// how much more likely one of the slowest words is in training.
+const SLOW_WORD_WEIGHT: f64 = 5.0;
+/// rows of the heatmap, keys not within the layout are shown in an additional row.
+const LAYOUT: [&str; 4] = ["1234567890", "qwertyuiop", "asdfghjkl", "zxcvbnm"];
+
+/// How often a key or bigram was typed right and wrong.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct KeyStats {
+  pub hits: u64,
+  pub errors: u64,
+}
+
+impl KeyStats {
+  /// ratio of errors to all attempts, if there are enough attempts.
+  pub fn error_rate(&self) -> Option<f64> {
+    let attempts = self.hits + self.errors;
+    (attempts >= MIN_ATTEMPTS).then(|| self.errors as f64 / attempts as f64)
+  }
+
+  fn add(&mut self, correct: bool) {
+    match correct {
+      true => self.hits += 1,
+      false => self.errors += 1,
+    }
+  }
+}
+
+/// How long it took to type a word.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct WordStats {
+  pub count: u64,
+  // sum of all times from the first key to the completion in ms
+  pub time: u128,
+}
+
+impl WordStats {
+  /// average time per character in ms.
+  pub fn time_per_char(&self, word: &str) -> f64 {
+    self.time as f64 / self.count.max(1) as f64 / word.chars().count().max(1) as f64
+  }
+}
+
+/// Error rates per key, shown in the layout of a keyboard. Keys without enough attempts have no rate.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Heatmap {
+  pub rows: Vec<Vec<(char, Option<f64>)>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct Data {
+  keys: BTreeMap<char, KeyStats>,
+  bigrams: BTreeMap<String, KeyStats>,
+  words: BTreeMap<String, WordStats>,
+}
+
+/// The strengths and weaknesses of the player over all sessions. When created with a path,
+/// they are stored on disk.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Training {
+  #[serde(skip)]
+  path: Option<PathBuf>,
+  data: Data,
+}
+
+impl Training {
+  /// Statistics that are forgotten when the game ends.
+  pub fn new() -> Self {
+    Training {
+      path: None,
+      data: Data::default(),
+    }
+  }
+
+  /// Loads the statistics from the given file. A missing file has no statistics.
+  pub fn load(path: PathBuf) -> Result<Self, String> {
+    let data = storage::load_json(&path, "training")?;
+    Ok(Training {
+      path: Some(path),
+      data,
+    })
+  }
+
+  /// A copy of the statistics that is forgotten when the game ends.
+  pub fn snapshot(&self) -> Self {
+    Training {
+      path: None,
+      data: self.data.clone(),
+    }
+  }
+
+  /// the training file within the data directory of the user.
+  pub fn default_path() -> Option<PathBuf> {
+    storage::data_file("training.json")
+  }
+
+  pub fn save(&self) -> Result<(), String> {
+    let path = match &self.path {
+      Some(path) => path,
+      None => return Ok(())
+    };
+    storage::save_json(path, "training", &self.data)
+  }
+
+  /// Adds the keys and words of a finished game.
+  pub fn add(&mut self, result: &WorldState) {
+    for keystroke in &result.keystrokes {
+      let expected = keystroke.expected.to_lowercase().next().unwrap_or(keystroke.expected);
+      self.data.keys.entry(expected).or_default().add(keystroke.correct);
+      if let Some(previous) = keystroke.previous {
+        let bigram: String = previous.to_lowercase().chain([expected]).collect();
+        self.data.bigrams.entry(bigram).or_default().add(keystroke.correct);
+      }
+    }
+    for word in &result.completed {
+      let stats = self.data.words.entry(word.word.clone()).or_default();
+      stats.count += 1;
+      stats.time += word.finished - word.first_key;
+    }
+  }
+
+  pub fn key(&self, key: char) -> KeyStats {
+    self.data.keys.get(&key).copied().unwrap_or_default()
+  }
+
+  /// the keys with the highest error rate, worst first.
+  pub fn weakest_keys(&self) -> Vec<(char, f64)> {
+    weakest(self.data.keys.iter().map(|(key, stats)| (*key, *stats)))
+  }
+
+  /// the bigrams with the highest error rate, worst first.
+  pub fn weakest_bigrams(&self) -> Vec<(String, f64)> {
+    weakest(self.data.bigrams.iter().map(|(bigram, stats)| (bigram.clone(), *stats)))
+  }
+
+  /// the words that took longer per character than the average, slowest first.
+  pub fn slowest_words(&self) -> Vec<(String, f64)> {
+    let mut words: Vec<(String, f64)> = self.data.words.iter()
+        .map(|(word, stats)| (word.clone(), stats.time_per_char(word)))
+        .collect();
+    let average = words.iter().map(|(_, time)| time).sum::<f64>() / words.len().max(1) as f64;
+    words.retain(|(_, time)| *time > average);
+    words.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+    words.truncate(WEAKEST_SIZE);
+    words
+  }
+
+  /// Weights of the given words for the training, words with weak keys, weak bigrams
+  /// or slow words are more likely. Every word keeps a weight of at least 1.
+  pub fn weights(&self, words: &[&String]) -> Vec<f64> {
+    let slowest = self.slowest_words();
+    let key_rate = |key: char| self.data.keys.get(&key).and_then(KeyStats::error_rate).unwrap_or(0.0);
+    let bigram_rate = |bigram: String| self.data.bigrams.get(&bigram).and_then(KeyStats::error_rate).unwrap_or(0.0);
+    words.iter().map(|word| {
+      let chars: Vec<char> = word.chars().flat_map(char::to_lowercase).collect();
+      let keys: f64 = chars.iter().map(|key| key_rate(*key)).sum();
+      let bigrams: f64 = chars.windows(2).map(|pair| bigram_rate(pair.iter().collect())).sum();
+      let slow = if slowest.iter().any(|(slow, _)| slow == *word) { SLOW_WORD_WEIGHT } else { 0.0 };
+      1.0 + KEY_WEIGHT * keys + BIGRAM_WEIGHT * bigrams + slow
+    }).collect()
+  }
+
+  pub fn heatmap(&self) -> Heatmap {
+    let rate = |key: char| self.key(key).error_rate();
+    let mut rows: Vec<Vec<(char, Option<f64>)>> = LAYOUT.iter()
+        .map(|row| row.chars().map(|key| (key, rate(key))).collect())
+        .collect();
+    let others: Vec<(char, Option<f64>)> = self.data.keys.keys()
+        .filter(|key| !LAYOUT.iter().any(|row| row.contains(**key)))
+        .map(|key| (*key, rate(*key)))
+        .collect();
+    if !others.is_empty() {
+      rows.push(others);
+    }
+    Heatmap { rows }
+  }
+}
+
+impl Default for Training {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+fn weakest<T>(stats: impl Iterator<Item=(T, KeyStats)>) -> Vec<(T, f64)> {
+  let mut rates: Vec<(T, f64)> = stats
+      .filter_map(|(key, stats)| stats.error_rate().filter(|rate| *rate > 0.0).map(|rate| (key, rate)))
+      .collect();
+  rates.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+  rates.truncate(WEAKEST_SIZE);
+  rates
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::training::{KeyStats, Training};
+  use crate::typeattack::{CompletedWord, Keystroke, WorldState};
+
+  fn keystroke(expected: char, previous: Option<char>, correct: bool) -> Keystroke {
+    Keystroke { expected, previous, correct }
+  }
+
+  /// a game where "x" fails every second time and the rest is typed right.
+  fn game() -> WorldState {
+    let mut state = WorldState::new();
+    for index in 0..10 {
+      state.keystrokes.push(keystroke('a', None, true));
+      state.keystrokes.push(keystroke('X', Some('a'), index % 2 == 0));
+    }
+    state.completed = vec![
+      CompletedWord { word: String::from("ax"), spawned: 0, first_key: 0, finished: 4000 },
+      CompletedWord { word: String::from("abc"), spawned: 0, first_key: 0, finished: 300 },
+    ];
+    state
+  }
+
+  #[test]
+  fn error_rate_needs_attempts() {
+    assert_eq!(KeyStats { hits: 3, errors: 1 }.error_rate(), None);
+    assert_eq!(KeyStats { hits: 3, errors: 2 }.error_rate(), Some(0.4));
+  }
+
+  #[test]
+  fn weakest() {
+    let mut training = Training::new();
+    training.add(&game());
+    assert_eq!(training.key('a'), KeyStats { hits: 10, errors: 0 });
+    assert_eq!(training.weakest_keys(), vec![('x', 0.5)]);
+    assert_eq!(training.weakest_bigrams(), vec![(String::from("ax"), 0.5)]);
+    assert_eq!(training.slowest_words(), vec![(String::from("ax"), 2000.0)]);
+  }
+
+  #[test]
+  fn weak_words_are_more_likely() {
+    let mut training = Training::new();
+    let words = [String::from("ax"), String::from("abc")];
+    let words: Vec<&String> = words.iter().collect();
+    assert_eq!(training.weights(&words), vec![1.0, 1.0]);
+    training.add(&game());
+    let weights = training.weights(&words);
+    assert!(weights[0] > weights[1]);
+    assert_eq!(weights[1], 1.0);
+  }
+
+  #[test]
+  fn heatmap() {
+    let mut training = Training::new();
+    let mut state = game();
+    state.keystrokes.extend(std::iter::repeat_n(keystroke('ß', None, true), 5));
+    training.add(&state);
+    let heatmap = training.heatmap();
+    assert_eq!(heatmap.rows.len(), 5);
+    assert!(heatmap.rows[2].contains(&('a', Some(0.0))));
+    assert!(heatmap.rows[3].contains(&('z', None)));
+    assert_eq!(heatmap.rows[4], vec![('ß', Some(0.0))]);
+  }
+
+  #[test]
+  fn missing_file_is_empty() {
+    let path = std::env::temp_dir().join("typeattack-missing-training.json");
+    let training = Training::load(path).unwrap();
+    assert!(training.weakest_keys().is_empty());
+  }
+
+  #[test]
+  fn save_and_load() {
+    let path = std::env::temp_dir().join(format!("typeattack-training-{}.json", std::process::id()));
+    let mut training = Training::load(path.clone()).unwrap();
+    training.add(&game());
+    training.save().unwrap();
+    let loaded = Training::load(path.clone()).unwrap();
+    std::fs::remove_file(path).unwrap();
+    assert_eq!(loaded.data, training.data);
+  }
+}
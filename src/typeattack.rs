@@ -1,18 +1,54 @@
+use std::path::PathBuf;
 use std::pin::Pin;
-use std::time::{Duration, Instant};
+use std::str::FromStr;
 
-use crate::words::WORDS;
+use crate::adaptive::{Adaptive, Difficulty, Progress};
+use crate::clock::{Clock, SystemClock};
+use crate::history::{self, History, Session};
+use crate::matching::Matching;
+use crate::placement;
+use crate::powerup::{Effect, PowerUp, DEFAULT_POWER_UP_CHANCE, SLOW_FACTOR};
+use crate::progression::Progression;
+use crate::reading::{Bookmarks, Reading};
+use crate::recording::{Entry, Recording};
+use crate::score;
+use crate::selection::Selection;
+use crate::spawning::{Schedule, Spawning};
+use crate::statistics::{CHARS_PER_WORD, MS_PER_MINUTE};
+use crate::text;
+use crate::training::{Heatmap, Training};
+use crate::wordlist::WordList;
 
-use async_std::stream::interval;
 use futures::{stream::select, StreamExt};
 use futures::executor::block_on;
 use futures::stream::Stream;
-use rand::prelude::ThreadRng;
-use rand::Rng;
+use rand::distributions::WeightedIndex;
+use rand::prelude::Distribution;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
 
 pub const DEFAULT_SPEED: f64 = 0.0001;
+pub const DEFAULT_LIVES: u16 = 3;
+/// duration of a sprint in ms, if there is no other duration set.
+pub const DEFAULT_SPRINT_DURATION: u128 = 60_000;
+/// the level increases every this many words.
+pub const DEFAULT_LEVEL_UP_WORDS: u128 = 10;
+/// one more word is on screen every this many levels.
+pub const DEFAULT_LEVELS_PER_WORD: usize = 5;
+/// a boss phrase appears every this many levels, if nothing else is set.
+pub const DEFAULT_BOSS_LEVELS: usize = 5;
+/// number of words in the phrase of the first boss, every following boss has one more.
+const BOSS_WORDS: usize = 4;
+/// factor on the speed of a boss.
+const BOSS_SPEED: f64 = 0.4;
+/// maximum length of a name in the high score table.
+pub const MAX_NAME_LENGTH: usize = 16;
+/// the columns and rows the words are placed on until a game measured the screen, those of the smallest terminal.
+pub const DEFAULT_SCREEN: (u16, u16) = (80, 22);
 
 /// Events the [RenderEngine.event_stream] needs to produce.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Event {
   // cancels the game
   Stop,
@@ -22,207 +58,862 @@ pub enum Event {
   RemoveChar,
   // Clears the buffer if necessary
   ClearBuffer,
+  // pauses the game, or resumes it when it is paused
+  Pause,
+  // the game is not visible anymore and should pause
+  FocusLost,
 }
 
 pub trait RenderEngine {
-  fn init(self: &Self) -> Result<(), String>;
+  fn init(&self) -> Result<(), String>;
 
   /// some stream of type Event
-  fn event_stream(self: &Self) -> Pin<Box<dyn Stream<Item=Event>>>;
+  fn event_stream(&self) -> Pin<Box<dyn Stream<Item=Event>>>;
 
-  fn draw_menu(self: &Self);
+  fn draw_menu(&self, highscores: &[Session]);
 
   /// when the game has an update, this method is
   /// called in order to update the ui.
-  fn draw_gamestate(self: &Self, state: &WorldState, old: &WorldState);
+  fn draw_gamestate(&self, state: &WorldState, old: &WorldState);
 
-  fn draw_result(self: &Self, result: &WorldState);
+  /// called when the game gets paused.
+  fn draw_pause(&self, state: &WorldState);
 
-  fn teardown(self: &Self);
+  /// the columns and rows the words fall on.
+  fn screen_size(&self) -> (u16, u16);
+
+  /// the width and height of the word on a screen with the given columns and rows,
+  /// as fractions of the screen width and height.
+  fn word_size(&self, word: &str, screen: (u16, u16)) -> (f64, f64);
+
+  /// shows the result of a game, with the error rates of all keys over all games.
+  fn draw_result(&self, result: &WorldState, highscores: &[Session], heatmap: &Heatmap);
+
+  /// called when a game made it into the high score table,
+  /// on every change of the name the user enters.
+  fn draw_name_entry(&self, name: &str);
+
+  fn teardown(&self);
+}
+
+/// The rules a game is played by.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Default)]
+pub enum Mode {
+  /// words fall faster and faster, until the player runs out of lives.
+  #[default]
+  Classic,
+  /// a typing test: the game ends after a fixed time, words hitting the ground only count as fails.
+  Sprint,
+  /// practice without pressure: the speed stays the same and the game only ends when the player leaves.
+  Zen,
+  /// like zen, but words with the keys and bigrams the player struggles with come up more often.
+  Training,
+  /// like zen, but the words of a text appear in order, until the text is done.
+  Text,
+}
+
+impl Mode {
+  /// the names of all modes.
+  pub fn names() -> Vec<&'static str> {
+    vec!["classic", "sprint", "zen", "training", "text"]
+  }
+}
+
+impl FromStr for Mode {
+  type Err = String;
+
+  fn from_str(name: &str) -> Result<Self, Self::Err> {
+    match name {
+      "classic" => Ok(Mode::Classic),
+      "sprint" => Ok(Mode::Sprint),
+      "zen" => Ok(Mode::Zen),
+      "training" => Ok(Mode::Training),
+      "text" => Ok(Mode::Text),
+      _ => Err(format!("Unknown mode {}, use one of: {}", name, Mode::names().join(", ")))
+    }
+  }
+}
+
+/// Everything that influences how a game plays, apart from the seed and the user input.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+  pub mode: Mode,
+  // falling speed of the words on level 1 in screen units per ms
+  pub speed: f64,
+  pub words: WordList,
+  // number of words that may hit the ground before the game is over
+  pub lives: u16,
+  pub start_level: usize,
+  // when set, the game ends after this many ms
+  pub duration: Option<u128>,
+  pub level_up_words: u128,
+  pub levels_per_word: usize,
+  // when set, there are never more words on screen
+  pub max_words: Option<usize>,
+  // when set, replaces the progression of the mode
+  pub progression: Option<Progression>,
+  // when set, the difficulty adapts to the player
+  pub adaptive: Option<Adaptive>,
+  pub matching: Matching,
+  // chance of a new word being a power-up
+  pub power_ups: f64,
+  // a boss phrase appears every this many levels, 0 for none
+  pub boss_levels: usize,
+  // the word of the text a game in text mode starts at
+  pub position: usize,
+  // how the words are picked
+  pub selection: Selection,
+  // when new words come, without it the screen is filled at once
+  pub spawning: Option<Spawning>,
+  // the columns and rows the words are placed on, measured when a game starts so that a replay places them alike
+  pub screen: (u16, u16),
+}
+
+impl Settings {
+  /// the time limit of a game in ms, if it has one.
+  pub fn time_limit(&self) -> Option<u128> {
+    match self.mode {
+      Mode::Classic | Mode::Zen | Mode::Training | Mode::Text => self.duration,
+      Mode::Sprint => self.duration.or(Some(DEFAULT_SPRINT_DURATION)),
+    }
+  }
+
+  /// the levels of a game.
+  pub fn progression(&self) -> Progression {
+    self.progression.clone()
+        .unwrap_or_else(|| Progression::for_mode(self.mode, self.levels_per_word, self.start_level))
+  }
+
+  /// Checks that the settings describe a playable game.
+  pub fn validate(&self) -> Result<(), String> {
+    if !(self.speed.is_finite() && self.speed > 0.0) {
+      return Err(format!("The speed needs to be a positive number, but is {}!", self.speed));
+    }
+    if self.words.words.is_empty() {
+      return Err(format!("The word list {} is empty!", self.words.name));
+    }
+    if self.lives == 0 {
+      return Err(String::from("There needs to be at least one life!"));
+    }
+    if self.start_level == 0 {
+      return Err(String::from("The first level is 1!"));
+    }
+    if self.duration == Some(0) {
+      return Err(String::from("The duration needs to be longer than 0s!"));
+    }
+    if self.level_up_words == 0 {
+      return Err(String::from("A level needs at least one word!"));
+    }
+    if self.levels_per_word == 0 {
+      return Err(String::from("Adding words needs at least one level!"));
+    }
+    if self.max_words == Some(0) {
+      return Err(String::from("There needs to be at least one word on screen!"));
+    }
+    if let Some(progression) = &self.progression {
+      progression.validate()?;
+    }
+    if let Some(adaptive) = &self.adaptive {
+      adaptive.validate()?;
+    }
+    self.selection.validate()?;
+    if let Some(spawning) = &self.spawning {
+      spawning.validate()?;
+    }
+    if !(0.0..=1.0).contains(&self.power_ups) {
+      return Err(format!("The chance of power-ups needs to be between 0 and 1, but is {}!", self.power_ups));
+    }
+    Ok(())
+  }
+}
+
+impl Default for Settings {
+  fn default() -> Self {
+    Settings {
+      mode: Mode::default(),
+      speed: DEFAULT_SPEED,
+      words: WordList::builtin(),
+      lives: DEFAULT_LIVES,
+      start_level: 1,
+      duration: None,
+      level_up_words: DEFAULT_LEVEL_UP_WORDS,
+      levels_per_word: DEFAULT_LEVELS_PER_WORD,
+      max_words: None,
+      progression: None,
+      adaptive: None,
+      matching: Matching::default(),
+      power_ups: DEFAULT_POWER_UP_CHANCE,
+      boss_levels: DEFAULT_BOSS_LEVELS,
+      position: 0,
+      selection: Selection::default(),
+      spawning: Some(Spawning::default()),
+      screen: DEFAULT_SCREEN,
+    }
+  }
 }
 
 pub struct Typeattack {
-  level: usize,
   engine: Box<dyn RenderEngine>,
-  random: ThreadRng,
+  clock: Box<dyn Clock>,
+  settings: Settings,
+  progression: Progression,
+  // when set, every game is started with this seed
+  seed: Option<u64>,
+  random: StdRng,
+  // number of words spawned in the current game
+  spawned: u64,
+  // the next word of the text in text mode
+  position: usize,
+  // the latest words picked in the current game, the last one is the latest
+  recent: Vec<String>,
+  // when set, every game is recorded into this file
+  recording: Option<PathBuf>,
+  history: History,
+  training: Training,
+  bookmarks: Bookmarks,
+  // problems that couldn't be shown while the game was running
+  warnings: Vec<String>,
 }
 
 impl Typeattack {
+  /// Creates a game running in real time, every game using a new random seed.
   pub fn new(engine: Box<dyn RenderEngine>) -> Self {
-    return Typeattack {
-      level: 1,
+    Typeattack::new_with_settings(engine, Settings::default(), None, Box::new(SystemClock::new()))
+  }
+
+  /// Creates a game with every game starting from the given seed. Combined with a
+  /// clock that doesn't depend on the wall clock, games are fully reproducible.
+  pub fn new_with_seed(engine: Box<dyn RenderEngine>, seed: u64, clock: Box<dyn Clock>) -> Self {
+    Typeattack::new_with_settings(engine, Settings::default(), Some(seed), clock)
+  }
+
+  pub fn new_with_settings(
+    engine: Box<dyn RenderEngine>,
+    settings: Settings,
+    seed: Option<u64>,
+    clock: Box<dyn Clock>,
+  ) -> Self {
+    Typeattack {
       engine,
-      random: rand::thread_rng(),
-    };
+      clock,
+      progression: settings.progression(),
+      settings,
+      seed,
+      random: StdRng::seed_from_u64(seed.unwrap_or(0)),
+      spawned: 0,
+      position: 0,
+      recent: vec![],
+      recording: None,
+      history: History::new(),
+      training: Training::new(),
+      bookmarks: Bookmarks::new(),
+      warnings: vec![],
+    }
   }
 
-  pub fn start(self: &mut Self) {
+  /// Uses the given history for the high score table and adds every finished game to it.
+  pub fn use_history(&mut self, history: History) {
+    self.history = history;
+  }
+
+  pub fn history(&self) -> &History {
+    &self.history
+  }
+
+  /// Uses the given statistics for the training mode and adds every finished game to them.
+  pub fn use_training(&mut self, training: Training) {
+    self.training = training;
+  }
+
+  pub fn training(&self) -> &Training {
+    &self.training
+  }
+
+  /// Uses the given bookmarks to remember where a text stopped, the settings need to start at its position.
+  pub fn use_bookmarks(&mut self, bookmarks: Bookmarks) {
+    self.bookmarks = bookmarks;
+  }
+
+  /// Records every following game into the given file.
+  /// The file only keeps the latest game.
+  pub fn record_to(&mut self, path: PathBuf) {
+    self.recording = Some(path);
+  }
+
+  pub fn start(&mut self) {
+    if let Err(error) = self.settings.validate() {
+      println!("{}", error);
+      return;
+    }
     if let Err(error) = self.engine.init() {
       println!("{}", error);
       return;
     }
     while block_on(self.show_menu()) {
-      let result = block_on(self.show_game());
+      let seed = self.seed.unwrap_or_else(rand::random);
+      self.settings.screen = self.engine.screen_size();
+      let result = block_on(self.show_game(seed));
+      block_on(self.save_session(&result));
       block_on(self.show_result(&result));
     }
     self.engine.teardown();
+    self.print_warnings();
   }
 
-  async fn show_menu(self: &Self) -> bool {
-    self.engine.draw_menu();
+  /// Plays back a recorded game in real time. The replay can be cancelled
+  /// using [Event::Stop], all other input is ignored.
+  /// A training game uses the statistics it was recorded with.
+  pub fn replay(&mut self, recording: &Recording) {
+    if let Some(training) = &recording.training {
+      self.training = training.clone();
+    }
+    if let Err(error) = self.settings.validate() {
+      println!("{}", error);
+      return;
+    }
+    if let Err(error) = self.engine.init() {
+      println!("{}", error);
+      return;
+    }
+    let cancel = self.engine.event_stream()
+        .filter(|event| futures::future::ready(*event == Event::Stop))
+        .map(StreamEvent::KeyEvent);
+    let stream = select(recording.stream(), cancel).boxed_local();
+    let result = block_on(self.play(recording.seed, stream));
+    block_on(self.show_result(&result));
+    self.engine.teardown();
+    self.print_warnings();
+  }
+
+  /// Shows the warning once the game is closed, as the screen of the game would hide it.
+  pub fn warn(&mut self, warning: String) {
+    self.warnings.push(warning);
+  }
+
+  fn print_warnings(&mut self) {
+    for warning in self.warnings.drain(..) {
+      println!("{}", warning);
+    }
+  }
+
+  async fn show_menu(&self) -> bool {
+    self.engine.draw_menu(&self.history.highscores(self.settings.mode));
     let mut input = self.engine.event_stream()
         .filter(|event| {
-          futures::future::ready(
-            match event {
-              Event::AddChar(_) => true,
-              Event::Stop => true,
-              Event::ClearBuffer => true,
-              _ => false
-            }
-          )
+          futures::future::ready(matches!(event, Event::AddChar(_) | Event::Stop | Event::ClearBuffer))
         });
-    if let Some(event) = input.next().await {
-      return match event {
-        Event::Stop => false,
-        _ => true
-      };
+    // an input stream that ended won't deliver any more events, so there is nothing left to play.
+    match input.next().await {
+      Some(event) => event != Event::Stop,
+      None => false
     }
-    true
   }
 
-  async fn show_game(self: &mut Self) -> WorldState {
-    // A timer that triggers updates of the ui 60 FPS ~ 16.666_7ms => 16ms
-    let timer = interval(Duration::from_millis(16))
-        .map(|_| StreamEvent::TimeUpdate);
+  async fn show_game(&mut self, seed: u64) -> WorldState {
+    // A timer that triggers updates of the ui
+    let timer = self.clock.ticks()
+        .map(StreamEvent::TimeUpdate);
     // A stream that delivers the input of the keyboard
     let input = self.engine.event_stream()
-        .map(|a| StreamEvent::KeyEvent(a));
-
-    let time = Instant::now();
-    let mut last = 0;
-    let mut world_state = WorldState::new();
+        .map(StreamEvent::KeyEvent);
 
     // unstable method: select
     // Create a stream that emits time updates and key events at the same time.
-    let mut stream = select(timer, input);
+    let stream = select(timer, input).boxed_local();
+    self.play(seed, stream).await
+  }
+
+  /// Runs a game on the given stream of events until it is over.
+  async fn play(&mut self, seed: u64, mut stream: Pin<Box<dyn Stream<Item=StreamEvent>>>) -> WorldState {
+    self.random = StdRng::seed_from_u64(seed);
+    self.spawned = 0;
+    self.recent.clear();
+    // a finished text starts over
+    let length = self.settings.words.words.len();
+    self.position = if self.settings.position < length { self.settings.position } else { 0 };
+    let mut last = 0;
+    let mut world_state = WorldState::new();
+    world_state.level = self.settings.start_level as u128;
+    world_state.lives = self.settings.lives;
+    world_state.time_limit = self.settings.time_limit();
+    world_state.matching = self.settings.matching;
+    world_state.difficulty = self.settings.adaptive.as_ref().map(|_| Difficulty::default());
+    if self.settings.mode == Mode::Text {
+      world_state.reading = Some(Reading { start: self.position, position: self.position, length });
+    }
+    let mut entries = Vec::new();
 
     while let Some(event) = stream.next().await {
+      if self.recording.is_some() {
+        entries.push(match &event {
+          StreamEvent::TimeUpdate(timestamp) => Entry::Tick(*timestamp),
+          StreamEvent::KeyEvent(key) => Entry::Key(last, key.clone()),
+        });
+      }
       match event {
-        StreamEvent::TimeUpdate => {
-          let timestamp = time.elapsed().as_millis();
+        // the time doesn't pass while the game is paused
+        StreamEvent::TimeUpdate(timestamp) if world_state.paused => last = timestamp,
+        StreamEvent::TimeUpdate(timestamp) => {
           let delta = timestamp - last;
           let new_world_state = self.update_world(delta, &world_state);
           self.engine.draw_gamestate(&new_world_state, &world_state);
           world_state = new_world_state;
           last = timestamp;
         }
+        StreamEvent::KeyEvent(Event::Stop) => break,
+        StreamEvent::KeyEvent(Event::Pause) if world_state.paused => {
+          world_state.paused = false;
+          self.engine.draw_gamestate(&world_state, &world_state);
+        }
+        StreamEvent::KeyEvent(Event::Pause | Event::FocusLost) if !world_state.paused => {
+          world_state.paused = true;
+          self.engine.draw_pause(&world_state);
+        }
+        // no input while the game is paused
+        StreamEvent::KeyEvent(_) if world_state.paused => {}
         StreamEvent::KeyEvent(key) => {
-          let mut new_world_state = world_state.clone();
-          match key {
-            Event::Stop => break,
-            Event::AddChar(c) => {
-              new_world_state.buffer.push(c);
-              let mut delete_buffer = true;
-              if new_world_state.buffer.len() > 0 {
-                for word in &new_world_state.words {
-                  if word.word.starts_with(&new_world_state.buffer) {
-                    delete_buffer = false;
-                    break;
-                  }
-                }
-              }
-              if delete_buffer {
-                new_world_state.buffer.pop();
-                // new_world_state.buffer.clear();
-              }
-              new_world_state.keycount += 1;
-            }
-            Event::RemoveChar => {
-              new_world_state.buffer.pop();
-              new_world_state.keycount += 1;
-            }
-            Event::ClearBuffer => {
-              new_world_state.buffer.clear();
-              new_world_state.keycount += 1;
-            }
-          }
-          let buffer = &new_world_state.buffer;
-          new_world_state.words.retain(|word| &word.word != buffer);
-          let removed_words = world_state.words.len() - new_world_state.words.len();
-          if removed_words > 0 {
-            new_world_state.buffer.clear();
-          }
-          new_world_state.wordcount += removed_words as u128;
+          let new_world_state = self.apply_key(key, &world_state);
           self.engine.draw_gamestate(&new_world_state, &world_state);
           world_state = new_world_state;
         }
       }
-      if world_state.fails >= 3 {
+      if self.is_over(&world_state) {
         break;
       }
     }
+    if let Some(path) = &self.recording {
+      // the statistics are only updated after the game, so they are still the ones it started with
+      let training = (self.settings.mode == Mode::Training).then(|| self.training.snapshot());
+      let recording = Recording { training, ..Recording::new(seed, self.settings.clone(), entries) };
+      if let Err(error) = recording.save(path) {
+        self.warnings.push(error);
+      }
+    }
     world_state
   }
 
-  async fn show_result(self: &Self, result: &WorldState) {
-    self.engine.draw_result(result);
-    self.engine.event_stream().next().await;
+  /// true, if the player ran out of lives or time, or all words of the text are gone.
+  /// In a sprint or in zen mode, lives don't count.
+  fn is_over(&self, world: &WorldState) -> bool {
+    let out_of_lives = match self.settings.mode {
+      Mode::Classic => world.fails >= world.lives,
+      Mode::Sprint | Mode::Zen | Mode::Training | Mode::Text => false,
+    };
+    let text_done = world.reading.is_some_and(|reading| reading.is_finished()) && world.words.is_empty();
+    out_of_lives || text_done || world.remaining_time() == Some(0)
+  }
+
+  /// Applies a user input to the world. Typing the last missing character of a
+  /// word removes it from the screen.
+  fn apply_key(&mut self, key: Event, world: &WorldState) -> WorldState {
+    let matching = self.settings.matching;
+    let mut new_world_state = world.clone();
+    // with a rule to submit words, the words are only finished by space or enter
+    let mut submitted = !matching.submit;
+    match key {
+      Event::Stop | Event::Pause | Event::FocusLost => {}
+      Event::AddChar(' ') if matching.submit && world.completes_word() => {
+        submitted = true;
+        new_world_state.keycount += 1;
+      }
+      Event::AddChar(c) => {
+        let mut buffer = new_world_state.buffer.clone();
+        buffer.push(c);
+        let buffer = text::normalize(&buffer);
+        let fitting: Vec<usize> = world.target_indices()
+            .filter(|index| matching.is_prefix(&world.words[*index].word, &buffer))
+            .collect();
+        let previous = world.buffer.chars().last();
+        if !fitting.is_empty() {
+          new_world_state.buffer = buffer;
+          new_world_state.accepted += 1;
+          new_world_state.keystrokes.push(Keystroke { expected: c, previous, correct: true });
+          // keep the target as long as it fits, otherwise take the word closest to the ground
+          if !fitting.iter().any(|index| Some(world.words[*index].id) == world.target) {
+            new_world_state.target = nearest(&world.words, &fitting).map(|index| world.words[index].id);
+          }
+          new_world_state.locked = world.locked || (matching.lock && fitting.len() == 1);
+        } else {
+          new_world_state.rejected += 1;
+          new_world_state.combo = 0;
+          // an error only counts for a key, if it is clear which key was meant
+          let mut expected = world.targets().filter_map(|word| text::next_char(&word.word, &world.buffer));
+          if let Some(first) = expected.next() {
+            if expected.all(|other| other == first) {
+              new_world_state.keystrokes.push(Keystroke { expected: first, previous, correct: false });
+            }
+          }
+          // the wrong key stays visible, until it is removed
+          if matching.strict {
+            new_world_state.buffer = buffer;
+          }
+        }
+        new_world_state.keycount += 1;
+      }
+      Event::RemoveChar => {
+        text::pop(&mut new_world_state.buffer);
+        new_world_state.keycount += 1;
+        new_world_state.combo = 0;
+      }
+      Event::ClearBuffer if matching.submit && world.completes_word() => {
+        submitted = true;
+        new_world_state.keycount += 1;
+      }
+      Event::ClearBuffer => {
+        new_world_state.buffer.clear();
+        new_world_state.keycount += 1;
+      }
+    }
+    // remember when the user started typing the current word
+    new_world_state.buffer_started = match new_world_state.buffer.is_empty() {
+      true => None,
+      false => world.buffer_started.or(Some(world.time)),
+    };
+    let finished = match submitted {
+      true => new_world_state.finished_index(),
+      false => None,
+    };
+    let completed: Vec<Word> = finished.map(|index| new_world_state.words.remove(index)).into_iter().collect();
+    if !completed.is_empty() {
+      new_world_state.buffer.clear();
+      new_world_state.buffer_started = None;
+    }
+    if new_world_state.buffer.is_empty() {
+      new_world_state.target = None;
+      new_world_state.locked = false;
+    }
+    new_world_state.wordcount += completed.len() as u128;
+    for word in completed {
+      let mut points = score::word_points(&word.word, word.y) * score::multiplier(new_world_state.combo) as u64;
+      if new_world_state.is_active(PowerUp::DoubleScore) {
+        points *= 2;
+      }
+      if word.boss {
+        points *= score::BOSS_FACTOR;
+      }
+      new_world_state.score += points;
+      if let Some(power_up) = word.power_up {
+        new_world_state.activate(power_up);
+      }
+      new_world_state.combo += 1;
+      new_world_state.best_combo = new_world_state.best_combo.max(new_world_state.combo);
+      new_world_state.completed.push(CompletedWord {
+        word: word.word,
+        spawned: word.spawned,
+        first_key: world.buffer_started.unwrap_or(world.time),
+        finished: world.time,
+      });
+    }
+    new_world_state
+  }
+
+  async fn show_result(&self, result: &WorldState) {
+    self.engine.draw_result(result, &self.history.highscores(self.settings.mode), &self.training.heatmap());
+    // losing the focus is no reason to leave the result
+    self.engine.event_stream()
+        .filter(|event| futures::future::ready(*event != Event::FocusLost))
+        .next().await;
   }
 
-  fn update_world(self: &mut Self, delta: u128, world: &WorldState) -> WorldState {
-    // add 1/10th of speed every level => level 10 -> double speed
-    let velocity = DEFAULT_SPEED * (1.0 + self.level as f64 / 10.0) as f64;
+  /// Adds the game to the history. If it made it into the high score table,
+  /// the user can enter a name for it.
+  async fn save_session(&mut self, result: &WorldState) {
+    let mut session = Session::new(result, self.settings.mode, history::now());
+    if self.history.is_highscore(&session) {
+      session.name = self.show_name_entry().await;
+    }
+    self.history.add(session);
+    if let Err(error) = self.history.save() {
+      self.warnings.push(error);
+    }
+    self.training.add(result);
+    if let Err(error) = self.training.save() {
+      self.warnings.push(error);
+    }
+    // the next game continues the text
+    if let Some(reading) = result.reading {
+      self.settings.position = reading.resume(&result.words);
+      self.bookmarks.set(&self.settings.words, self.settings.position);
+      if let Err(error) = self.bookmarks.save() {
+        self.warnings.push(error);
+      }
+    }
+  }
+
+  /// Lets the user enter a name. Returns [None] if the user skipped it.
+  async fn show_name_entry(&self) -> Option<String> {
+    let mut name = self.history.last_name().unwrap_or_default();
+    let mut input = self.engine.event_stream();
+    self.engine.draw_name_entry(&name);
+    while let Some(event) = input.next().await {
+      match event {
+        Event::Stop => return None,
+        Event::AddChar(c) => {
+          if name.chars().count() < MAX_NAME_LENGTH {
+            name.push(c);
+          }
+        }
+        Event::RemoveChar => {
+          text::pop(&mut name);
+        }
+        Event::ClearBuffer => {
+          let name = name.trim();
+          return (!name.is_empty()).then(|| String::from(name));
+        }
+        Event::Pause | Event::FocusLost => {}
+      }
+      self.engine.draw_name_entry(&name);
+    }
+    None
+  }
+
+  fn update_world(&mut self, delta: u128, world: &WorldState) -> WorldState {
+    let difficulty = world.difficulty.clone().unwrap_or_default();
+    let mut velocity = self.settings.speed * self.progression.level(world.level as usize).speed * difficulty.speed();
+    if world.is_active(PowerUp::Freeze) {
+      velocity = 0.0;
+    } else if world.is_active(PowerUp::Slow) {
+      velocity *= SLOW_FACTOR;
+    }
     // v = 1.0(screen_unit) / 10000ms = 0.0001 screen_unit/ms
     // delta_s = v * delta_t
     let delta_s = velocity * delta as f64;
     let mut words: Vec<Word> = Vec::new();
     let mut new_fails: u16 = 0;
-    for i in 0..world.words.len() {
-      let word: &Word = &world.words[i];
+    let time = world.time + delta;
+    for word in &world.words {
+      let delta_s = delta_s * word.speed;
       if word.y + delta_s < 1.0 {
-        words.push(Word::new(word.word.as_str().clone(), word.x, word.y + delta_s));
+        words.push(Word { y: word.y + delta_s, ..word.clone() });
       } else {
         new_fails += 1;
       }
     }
     // level + 1, for each 10 words
-    self.level = (world.wordcount / 10) as usize + 1;
-    // add 1 additional word every 5 levels
-    while words.len() < (self.level / 5) + 1 {
-      words.push(self.spawn_word())
+    let level = (world.wordcount / self.settings.level_up_words) as usize + self.settings.start_level;
+    let lives = world.lives + self.progression.lives_bonus(world.level as usize, level);
+    let word_count = difficulty.words(self.progression.level(level).words);
+    let word_count = self.settings.max_words.map_or(word_count, |max| word_count.min(max));
+    // a boss comes on top of the other words
+    let missing = word_count.saturating_sub(words.iter().filter(|word| !word.boss).count());
+    let count = match self.settings.spawning {
+      Some(spawning) => spawning.due(&world.schedule, time, missing, &mut self.random),
+      // without spawning the screen is filled at once
+      None => missing,
+    };
+    let mut spawned = 0;
+    for _ in 0..count {
+      match self.spawn_word(level, &difficulty, time, &words) {
+        Some(word) => words.push(word),
+        None => break,
+      }
+      spawned += 1;
+    }
+    let pace = self.progression.level(level).speed * difficulty.speed();
+    let schedule = match self.settings.spawning {
+      Some(spawning) if spawned > 0 => spawning.after(&world.schedule, spawned, time, pace, &mut self.random),
+      _ => world.schedule,
+    };
+    // a text keeps its order without bosses
+    let boss_levels = self.settings.boss_levels;
+    if boss_levels > 0 && self.settings.mode != Mode::Text
+        && (world.level as usize + 1..=level).any(|level| level % boss_levels == 0) {
+      let boss = self.spawn_boss(level, &difficulty, time, &words);
+      words.extend(boss);
     }
+    let fails = world.fails + new_fails;
+    let (score, combo) = match new_fails {
+      0 => (world.score, world.combo),
+      _ => (world.score.saturating_sub(score::MISS_PENALTY * new_fails as u64), 0),
+    };
+    let difficulty = world.difficulty.clone().map(|mut difficulty| {
+      if let Some(adaptive) = &self.settings.adaptive {
+        let progress = Progress { time, words: world.wordcount, fails, accepted: world.accepted };
+        difficulty.evaluate(adaptive, progress, Self::demand_wpm(&words, velocity));
+      }
+      difficulty
+    });
+    // a word hitting the ground releases the lock on it
+    let target = world.target.filter(|id| words.iter().any(|word| word.id == *id));
+    let effects = world.effects.iter().copied().filter(|effect| effect.until > time).collect();
+    let reading = world.reading.map(|reading| Reading { position: self.position, ..reading });
     WorldState {
       words,
-      buffer: world.buffer.clone(),
-      fails: world.fails + new_fails,
-      wordcount: world.wordcount,
-      keycount: world.keycount,
-      level: world.level,
+      target,
+      locked: world.locked && target.is_some(),
+      effects,
+      fails,
+      score,
+      combo,
+      lives,
+      level: level as u128,
+      time,
+      difficulty,
+      reading,
+      schedule,
+      ..world.clone()
     }
   }
 
-  fn spawn_word(self: &mut Self) -> Word {
-    Word {
-      word: String::from(WORDS[self.random.gen_range(0..WORDS.len())]),
-      x: self.random.gen_range(0.0..1.0),
+  /// the WPM needed to type all words on screen before they hit the ground.
+  fn demand_wpm(words: &[Word], velocity: f64) -> f64 {
+    // every word needs 1 / (velocity * speed) ms to fall down
+    let chars_per_ms: f64 = words.iter().map(|word| word.word.chars().count() as f64 * word.speed * velocity).sum();
+    chars_per_ms / CHARS_PER_WORD * MS_PER_MINUTE
+  }
+
+  /// a new word at the top of the screen, [None] at the end of a text.
+  fn spawn_word(&mut self, level: usize, difficulty: &Difficulty, time: u128, on_screen: &[Word]) -> Option<Word> {
+    let word = self.pick_word(level, difficulty, on_screen)?;
+    self.spawned += 1;
+    let x = self.place(&word, on_screen);
+    // without power-ups the random numbers stay the same as before there were any,
+    // a text has none, as clearing the screen would skip its words
+    let power_ups = self.settings.power_ups > 0.0 && self.settings.mode != Mode::Text;
+    let power_up = match power_ups && self.random.gen_bool(self.settings.power_ups) {
+      true => Some(PowerUp::ALL[self.random.gen_range(0..PowerUp::ALL.len())]),
+      false => None,
+    };
+    let speed = self.settings.spawning.map_or(1.0, |spawning| spawning.speed(&mut self.random));
+    Some(Word {
+      id: self.spawned,
+      word,
+      x,
+      y: 0.0,
+      spawned: time,
+      speed,
+      power_up,
+      boss: false,
+    })
+  }
+
+  /// a boss with a phrase of words that fit the level, which gets longer with every boss.
+  fn spawn_boss(&mut self, level: usize, difficulty: &Difficulty, time: u128, on_screen: &[Word]) -> Option<Word> {
+    let count = BOSS_WORDS + level / self.settings.boss_levels.max(1) - 1;
+    let phrase: Vec<String> = (0..count)
+        .map(|_| self.pick_word(level, difficulty, on_screen))
+        .collect::<Option<_>>()?;
+    self.spawned += 1;
+    let phrase = phrase.join(" ");
+    Some(Word {
+      id: self.spawned,
+      x: self.place(&phrase, on_screen),
+      word: phrase,
       y: 0.0,
+      spawned: time,
+      speed: BOSS_SPEED,
+      power_up: None,
+      boss: true,
+    })
+  }
+
+  /// A random x for a new word, where it doesn't overlap the words on screen if there is room for it.
+  /// Only the words in the rows of the new word are in the way, and the slower bosses all the way down.
+  fn place(&mut self, word: &str, on_screen: &[Word]) -> f64 {
+    let screen = self.settings.screen;
+    let (width, height) = self.engine.word_size(word, screen);
+    // keep a space between the words
+    let (gap, _) = self.engine.word_size(" ", screen);
+    let taken: Vec<(f64, f64)> = on_screen.iter()
+        .filter(|other| other.y < height || other.boss)
+        .map(|other| {
+          let (start, end) = placement::span(other.x, self.engine.word_size(&other.word, screen).0);
+          (start - gap, end + gap)
+        })
+        .collect();
+    // always one random number, so that the x doesn't change which words come next
+    placement::place(self.random.gen_range(0.0..1.0), width, &taken)
+  }
+
+  /// A random word allowed on the level, following the selection and, in training, the weak keys.
+  /// A text gives its words in order, until there are no more.
+  fn pick_word(&mut self, level: usize, difficulty: &Difficulty, on_screen: &[Word]) -> Option<String> {
+    if self.settings.mode == Mode::Text {
+      let word = self.settings.words.words.get(self.position).cloned();
+      if word.is_some() {
+        self.position += 1;
+      }
+      return word;
+    }
+    let level = self.progression.level(level);
+    let mut words: Vec<&String> = self.settings.words.words.iter()
+        .filter(|word| level.allows(word) && difficulty.allows(word))
+        .collect();
+    // rather any word than none at all
+    if words.is_empty() {
+      words = self.settings.words.words.iter().collect();
     }
+    let on_screen: Vec<&str> = on_screen.iter().map(|word| word.word.as_str()).collect();
+    let selection = self.settings.selection;
+    let mut weights = selection.weights(&self.settings.words, &words, &self.recent, &on_screen);
+    if self.settings.mode == Mode::Training {
+      weights = weights.iter().zip(self.training.weights(&words)).map(|(a, b)| a * b).collect();
+    }
+    let index = WeightedIndex::new(weights).map_or(0, |weights| weights.sample(&mut self.random));
+    let word = words[index].clone();
+    self.recent.push(word.clone());
+    self.recent.drain(..self.recent.len().saturating_sub(selection.recent));
+    Some(word)
   }
 }
 
-enum StreamEvent {
-  TimeUpdate,
+/// the index of the word closest to the ground among the candidates, the first one of equally close words.
+fn nearest(words: &[Word], candidates: &[usize]) -> Option<usize> {
+  candidates.iter().copied().fold(None, |nearest: Option<usize>, index| match nearest {
+    Some(nearest) if words[nearest].y >= words[index].y => Some(nearest),
+    _ => Some(index),
+  })
+}
+
+pub(crate) enum StreamEvent {
+  // the elapsed time since the game started in ms
+  TimeUpdate(u128),
   KeyEvent(Event),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct WorldState {
   pub words: Vec<Word>,
   pub buffer: String,
+  // the id of the word the typed text belongs to
+  pub target: Option<u64>,
+  // when locked, the typed text can't switch to another word
+  pub locked: bool,
+  pub matching: Matching,
   pub fails: u16,
+  // number of fails that end a classic game
+  pub lives: u16,
   pub wordcount: u128,
   pub keycount: u128,
+  pub score: u64,
+  // words typed in a row without a rejected key, a removed character or a miss
+  pub combo: u32,
+  pub best_combo: u32,
   pub level: u128,
+  // elapsed time since the game started in ms
+  pub time: u128,
+  // characters that matched a word on screen
+  pub accepted: u128,
+  // characters that didn't match any word on screen
+  pub rejected: u128,
+  // when set, the game ends at this time
+  pub time_limit: Option<u128>,
+  pub paused: bool,
+  // time of the first key of the current buffer
+  pub buffer_started: Option<u128>,
+  pub completed: Vec<CompletedWord>,
+  // every character typed while it was clear which key was meant
+  pub keystrokes: Vec<Keystroke>,
+  // only set when the difficulty adapts to the player
+  pub difficulty: Option<Difficulty>,
+  // power-ups that are active for a while
+  pub effects: Vec<Effect>,
+  // only set in text mode
+  pub reading: Option<Reading>,
+  // when the next words come
+  pub schedule: Schedule,
 }
 
 impl WorldState {
@@ -230,27 +921,892 @@ impl WorldState {
     WorldState {
       words: vec![],
       buffer: String::new(),
+      target: None,
+      locked: false,
+      matching: Matching::default(),
       fails: 0,
+      lives: DEFAULT_LIVES,
       wordcount: 0,
       keycount: 0,
+      score: 0,
+      combo: 0,
+      best_combo: 0,
       level: 1,
+      time: 0,
+      accepted: 0,
+      rejected: 0,
+      time_limit: None,
+      paused: false,
+      buffer_started: None,
+      completed: vec![],
+      keystrokes: vec![],
+      difficulty: None,
+      effects: vec![],
+      reading: None,
+      schedule: Schedule::default(),
     }
   }
 }
 
-#[derive(Debug, Clone)]
+impl WorldState {
+  /// the words the typed text may belong to: the locked word, or all words.
+  pub fn targets(&self) -> impl Iterator<Item=&Word> {
+    self.target_indices().map(|index| &self.words[index])
+  }
+
+  fn target_indices(&self) -> impl Iterator<Item=usize> + '_ {
+    (0..self.words.len()).filter(|index| !self.locked || self.target == Some(self.words[*index].id))
+  }
+
+  /// the word the typed text belongs to.
+  pub fn target_word(&self) -> Option<&Word> {
+    self.words.iter().find(|word| Some(word.id) == self.target)
+  }
+
+  /// true, if the typed text is a word that may be finished.
+  pub fn completes_word(&self) -> bool {
+    self.finished_index().is_some()
+  }
+
+  /// The index of the word the typed text finishes: the target, or the complete word closest to the ground.
+  /// A boss being typed isn't interrupted by the words its phrase starts with.
+  fn finished_index(&self) -> Option<usize> {
+    let complete: Vec<usize> = self.target_indices()
+        .filter(|index| self.matching.is_complete(&self.words[*index].word, &self.buffer))
+        .collect();
+    match complete.iter().copied().find(|index| Some(self.words[*index].id) == self.target) {
+      Some(index) => Some(index),
+      None if self.target_word().is_some_and(|word| word.boss) => None,
+      None => nearest(&self.words, &complete),
+    }
+  }
+
+  /// the part of the word matching the typed text, if the word is the target.
+  pub fn typed_part<'a>(&self, word: &'a Word) -> Option<&'a str> {
+    (self.target == Some(word.id) && self.matching.is_prefix(&word.word, &self.buffer))
+        .then(|| text::typed_part(&word.word, &self.buffer))
+  }
+
+  /// false, if the typed text contains a rejected key, which only happens with strict matching.
+  pub fn is_buffer_valid(&self) -> bool {
+    self.buffer.is_empty() || self.targets().any(|word| self.matching.is_prefix(&word.word, &self.buffer))
+  }
+
+  /// true, if the power-up is in effect.
+  pub fn is_active(&self, power_up: PowerUp) -> bool {
+    self.effects.iter().any(|effect| effect.power_up == power_up && effect.until > self.time)
+  }
+
+  /// triggers the power-up, a timed one that is already active starts again.
+  fn activate(&mut self, power_up: PowerUp) {
+    match power_up {
+      PowerUp::Clear => {
+        self.words.clear();
+        self.target = None;
+        self.locked = false;
+      }
+      PowerUp::Life => self.lives += 1,
+      PowerUp::Slow | PowerUp::Freeze | PowerUp::DoubleScore => {
+        let until = self.time + power_up.duration().unwrap_or_default();
+        self.effects.retain(|effect| effect.power_up != power_up);
+        self.effects.push(Effect { power_up, until });
+      }
+    }
+  }
+
+  /// the remaining time in ms, if the game has a time limit.
+  pub fn remaining_time(&self) -> Option<u128> {
+    self.time_limit.map(|limit| limit.saturating_sub(self.time))
+  }
+}
+
+impl Default for WorldState {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct Word {
+  // identifies the word within a game
+  pub id: u64,
   pub word: String,
   pub x: f64,
   pub y: f64,
+  // time the word appeared on screen
+  pub spawned: u128,
+  // factor on the falling speed of the level
+  pub speed: f64,
+  // typing the word triggers this power-up
+  pub power_up: Option<PowerUp>,
+  // a long phrase that falls slowly and gives more points
+  pub boss: bool,
 }
 
 impl Word {
   pub fn new(value: &str, x: f64, y: f64) -> Self {
-    return Self {
+    Self {
+      id: 0,
       word: String::from(value),
       x,
       y,
+      spawned: 0,
+      speed: 1.0,
+      power_up: None,
+      boss: false,
+    }
+  }
+}
+
+/// A word the user typed successfully. All times are in ms since the game started.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompletedWord {
+  pub word: String,
+  pub spawned: u128,
+  pub first_key: u128,
+  pub finished: u128,
+}
+
+/// A typed character, either the expected one or a wrong key instead of it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keystroke {
+  pub expected: char,
+  // the character typed before, if it belongs to the same word
+  pub previous: Option<char>,
+  pub correct: bool,
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::adaptive::{Adaptive, Difficulty};
+  use crate::clock::StepClock;
+  use crate::headless::{Frame, Headless};
+  use crate::matching::Matching;
+  use crate::placement;
+  use crate::powerup::{Effect, PowerUp};
+  use crate::progression::{Level, Progression};
+  use crate::reading::Bookmarks;
+  use crate::score;
+  use crate::selection::Selection;
+  use crate::spawning::Spawning;
+  use crate::text;
+  use crate::training::Training;
+  use crate::wordlist::{WordFormat, WordList};
+  use crate::typeattack::{Event, Keystroke, Mode, Settings, Typeattack, Word, WorldState, DEFAULT_LIVES};
+
+  /// starts a single game, which runs until 3 words hit the ground.
+  fn play(seed: u64) -> Vec<Frame> {
+    let engine = Headless::new(vec![Event::AddChar('s')]);
+    Typeattack::new_with_seed(Box::new(engine.clone()), seed, Box::new(StepClock::new(16))).start();
+    engine.frames()
+  }
+
+  #[test]
+  fn same_seed_plays_same_game() {
+    let frames = play(42);
+    assert!(frames.len() > 3);
+    assert_eq!(frames, play(42));
+  }
+
+  #[test]
+  fn different_seed_plays_different_game() {
+    assert_ne!(play(42), play(43));
+  }
+
+  #[test]
+  fn typing_a_word_completes_it() {
+    let word = match &play(5)[1] {
+      Frame::GameState(state) => state.words[0].word.clone(),
+      frame => panic!("unexpected frame {:?}", frame)
+    };
+    let mut events = vec![Event::AddChar('s')];
+    events.extend(word.chars().map(Event::AddChar));
+    let engine = Headless::new(events);
+    Typeattack::new_with_seed(Box::new(engine.clone()), 5, Box::new(StepClock::new(16))).start();
+
+    let result = engine.world_states().pop().unwrap();
+    assert_eq!(result.wordcount, 1);
+    assert_eq!(result.accepted, word.chars().count() as u128);
+    assert_eq!(result.rejected, 0);
+    assert_eq!(result.completed.len(), 1);
+    assert_eq!(result.completed[0].word, word);
+    assert_eq!(result.completed[0].spawned, 16);
+    assert_eq!(result.completed[0].first_key, 16);
+  }
+
+  #[test]
+  fn highscore_asks_for_a_name() {
+    let word = match &play(5)[1] {
+      Frame::GameState(state) => state.words[0].word.clone(),
+      frame => panic!("unexpected frame {:?}", frame)
     };
+    let mut events = vec![Event::AddChar('s')];
+    events.extend(word.chars().map(Event::AddChar));
+    events.extend(vec![Event::Stop, Event::AddChar('A'), Event::AddChar('l'), Event::ClearBuffer]);
+    let engine = Headless::new(events);
+    let mut game = Typeattack::new_with_seed(Box::new(engine.clone()), 5, Box::new(StepClock::new(16)));
+    game.start();
+
+    let names: Vec<Frame> = engine.frames().into_iter()
+        .filter(|frame| matches!(frame, Frame::NameEntry(_)))
+        .collect();
+    assert_eq!(names, vec![
+      Frame::NameEntry(String::from("")),
+      Frame::NameEntry(String::from("A")),
+      Frame::NameEntry(String::from("Al")),
+    ]);
+    let sessions = game.history.sessions();
+    assert_eq!(sessions.len(), 1);
+    assert_eq!(sessions[0].name, Some(String::from("Al")));
+    assert_eq!(sessions[0].words, 1);
+  }
+
+  #[test]
+  fn game_ends_after_three_fails() {
+    let engine = Headless::new(vec![Event::AddChar('s')]);
+    Typeattack::new_with_seed(Box::new(engine.clone()), 1, Box::new(StepClock::new(16))).start();
+    let states = engine.world_states();
+    assert_eq!(states.last().unwrap().fails, 3);
+    assert_eq!(states.iter().filter(|state| state.fails == 3).count(), 1);
+  }
+
+  /// plays a single game with the given settings and returns its final state.
+  /// a game with the given settings and the seed 1, without any input.
+  fn game(settings: Settings) -> Typeattack {
+    Typeattack::new_with_settings(Box::new(Headless::new(vec![])), settings, Some(1), Box::new(StepClock::new(16)))
+  }
+
+  fn play_with(settings: Settings) -> WorldState {
+    let engine = Headless::new(vec![Event::AddChar('s')]);
+    Typeattack::new_with_settings(Box::new(engine.clone()), settings, Some(1), Box::new(StepClock::new(16))).start();
+    engine.world_states().pop().unwrap()
+  }
+
+  #[test]
+  fn sprint_ignores_misses() {
+    let settings = Settings { mode: Mode::Sprint, duration: Some(20_000), speed: 0.001, ..Settings::default() };
+    let result = play_with(settings);
+    assert!(result.fails > 3);
+    assert_eq!(result.remaining_time(), Some(0));
+  }
+
+  #[test]
+  fn sprint_lasts_a_minute() {
+    let result = play_with(Settings { mode: Mode::Sprint, ..Settings::default() });
+    assert_eq!(result.time_limit, Some(60_000));
+    assert_eq!(result.remaining_time(), Some(0));
+  }
+
+  #[test]
+  fn zen_ends_only_when_leaving() {
+    let mut events = vec![Event::AddChar('s')];
+    events.extend(std::iter::repeat_n(Event::RemoveChar, 3000));
+    events.push(Event::Stop);
+    let engine = Headless::new(events);
+    let settings = Settings { mode: Mode::Zen, speed: 0.001, ..Settings::default() };
+    Typeattack::new_with_settings(Box::new(engine.clone()), settings, Some(1), Box::new(StepClock::new(16))).start();
+    let result = engine.world_states().pop().unwrap();
+    assert!(result.fails > 3);
+    assert_eq!(result.keycount, 3000);
+  }
+
+  #[test]
+  fn pausing_stops_the_time() {
+    let mut events = vec![Event::AddChar('s'), Event::Pause];
+    events.extend(std::iter::repeat_n(Event::RemoveChar, 100));
+    events.extend([Event::Pause, Event::Stop]);
+    let engine = Headless::new(events);
+    Typeattack::new_with_seed(Box::new(engine.clone()), 1, Box::new(StepClock::new(16))).start();
+    let paused = engine.frames().into_iter().find_map(|frame| match frame {
+      Frame::Pause(state) => Some(state),
+      _ => None,
+    }).unwrap();
+    assert!(paused.paused);
+    let result = engine.world_states().pop().unwrap();
+    assert!(!result.paused);
+    // the keys while paused are ignored and only the ticks around the pause count
+    assert_eq!(result.keycount, 0);
+    assert!(result.time <= paused.time + 32);
+  }
+
+  #[test]
+  fn losing_the_focus_pauses() {
+    let mut events = vec![Event::AddChar('s'), Event::FocusLost];
+    events.extend(std::iter::repeat_n(Event::RemoveChar, 100));
+    events.push(Event::Stop);
+    let engine = Headless::new(events);
+    Typeattack::new_with_seed(Box::new(engine.clone()), 1, Box::new(StepClock::new(16))).start();
+    let frames = engine.frames();
+    let pause = frames.iter().position(|frame| matches!(frame, Frame::Pause(state) if state.time < 100)).unwrap();
+    // the game stays paused until it is left
+    assert!(!frames[pause..].iter().any(|frame| matches!(frame, Frame::GameState(_))));
+  }
+
+  #[test]
+  fn level_follows_the_words() {
+    let mut world = WorldState::new();
+    world.wordcount = 25;
+    let mut game = game(Settings::default());
+    assert_eq!(game.update_world(16, &world).level, 3);
+  }
+
+  #[test]
+  fn levels_come_from_the_progression() {
+    let levels = vec![
+      Level::default(),
+      Level { words: 3, min_length: 10, lives_bonus: 2, ..Level::default() },
+    ];
+    let settings = Settings {
+      level_up_words: 1,
+      progression: Some(Progression::new(levels).unwrap()),
+      spawning: None,
+      ..Settings::default()
+    };
+    let mut world = WorldState::new();
+    world.wordcount = 1;
+    let mut game = game(settings);
+    let world = game.update_world(16, &world);
+    assert_eq!(world.level, 2);
+    assert_eq!(world.lives, DEFAULT_LIVES + 2);
+    assert_eq!(world.words.len(), 3);
+    assert!(world.words.iter().all(|word| word.word.chars().count() >= 10));
+  }
+
+  #[test]
+  fn adaptive_difficulty_gets_easier_on_misses() {
+    let mut events = vec![Event::AddChar('s')];
+    events.extend(std::iter::repeat_n(Event::RemoveChar, 500));
+    events.push(Event::Stop);
+    let engine = Headless::new(events);
+    let settings = Settings {
+      mode: Mode::Zen,
+      speed: 0.01,
+      adaptive: Some(Adaptive { interval: 1000, ..Adaptive::default() }),
+      ..Settings::default()
+    };
+    Typeattack::new_with_settings(Box::new(engine.clone()), settings, Some(1), Box::new(StepClock::new(16))).start();
+    let difficulty = engine.world_states().pop().unwrap().difficulty.unwrap();
+    assert!(difficulty.step < 0);
+    assert!(difficulty.decisions.iter().all(|decision| decision.miss_rate == 1.0));
+  }
+
+  #[test]
+  fn keystrokes_know_the_expected_key() {
+    let mut world = WorldState::new();
+    world.words = vec![Word::new("test", 0.5, 0.0), Word::new("tea", 0.5, 0.0)];
+    world.buffer = String::from("te");
+    let mut game = game(Settings::default());
+    // "s" or "a" could be meant
+    assert!(game.apply_key(Event::AddChar('x'), &world).keystrokes.is_empty());
+    world.words.pop();
+    let world = game.apply_key(Event::AddChar('x'), &world);
+    assert_eq!(world.keystrokes, vec![Keystroke { expected: 's', previous: Some('e'), correct: false }]);
+    let world = game.apply_key(Event::AddChar('s'), &world);
+    assert_eq!(world.keystrokes[1], Keystroke { expected: 's', previous: Some('e'), correct: true });
+  }
+
+  #[test]
+  fn training_prefers_weak_keys() {
+    let mut weak = WorldState::new();
+    weak.keystrokes = vec![Keystroke { expected: 'q', previous: None, correct: false }; 10];
+    let spawned = |mode: Mode| {
+      let mut training = Training::new();
+      training.add(&weak);
+      // every word is equally likely, so that only the training counts
+      let selection = Selection { frequency: 0.0, recent: 0, prefix: 0 };
+      let settings = Settings { mode, speed: 0.01, power_ups: 0.0, selection, ..Settings::default() };
+      let mut events = vec![Event::AddChar('s')];
+      events.extend(std::iter::repeat_n(Event::RemoveChar, 500));
+      events.push(Event::Stop);
+      let engine = Headless::new(events);
+      let mut game = Typeattack::new_with_settings(Box::new(engine.clone()), settings, Some(1), Box::new(StepClock::new(16)));
+      game.use_training(training);
+      game.start();
+      let mut words: Vec<String> = engine.world_states().into_iter()
+          .flat_map(|state| state.words.into_iter().map(|word| format!("{}@{}", word.word, word.spawned)))
+          .collect();
+      words.dedup();
+      words.iter().filter(|word| word.contains('q')).count() as f64 / words.len() as f64
+    };
+    // only a few english words contain a "q"
+    assert!(spawned(Mode::Training) > 0.05);
+    assert!(spawned(Mode::Zen) < 0.01);
+  }
+
+  #[test]
+  fn combos_multiply_the_score() {
+    let mut world = WorldState::new();
+    world.combo = 5;
+    world.words = vec![Word::new("test", 0.5, 0.5), Word::new("tea", 0.5, 0.0)];
+    let mut game = game(Settings::default());
+    world.buffer = String::from("tes");
+    let typed = game.apply_key(Event::AddChar('t'), &world);
+    assert_eq!(typed.score, 2 * 60);
+    assert_eq!(typed.combo, 6);
+    assert_eq!(typed.best_combo, 6);
+    // a backspace breaks the combo
+    let corrected = game.apply_key(Event::RemoveChar, &world);
+    assert_eq!(corrected.combo, 0);
+    let corrected = game.apply_key(Event::AddChar('s'), &corrected);
+    let typed = game.apply_key(Event::AddChar('t'), &corrected);
+    assert_eq!(typed.score, 60);
+  }
+
+  #[test]
+  fn misses_cost_points() {
+    let mut world = WorldState::new();
+    world.score = 80;
+    world.combo = 3;
+    world.words = vec![Word::new("test", 0.5, 0.999)];
+    let mut game = game(Settings::default());
+    let world = game.update_world(1000, &world);
+    assert_eq!(world.score, 80 - score::MISS_PENALTY);
+    assert_eq!(world.combo, 0);
+    let mut world = game.update_world(1000, &world);
+    world.words = vec![Word::new("test", 0.5, 0.999)];
+    assert_eq!(game.update_world(1000, &world).score, 0);
+  }
+
+  /// types the text with the given rules on a screen with the given words, which get the ids 1, 2, ...
+  fn type_text(matching: Matching, words: &[&str], text: &str) -> WorldState {
+    let settings = Settings { matching, ..Settings::default() };
+    let mut game = game(settings);
+    let mut world = WorldState::new();
+    world.matching = matching;
+    world.words = words.iter().zip(1..).map(|(word, id)| Word { id, ..Word::new(word, 0.5, 0.0) }).collect();
+    for c in text.chars() {
+      let key = match c {
+        '<' => Event::RemoveChar,
+        '\n' => Event::ClearBuffer,
+        c => Event::AddChar(c),
+      };
+      world = game.apply_key(key, &world);
+    }
+    world
+  }
+
+  #[test]
+  fn target_is_closest_to_the_ground() {
+    let mut game = game(Settings::default());
+    let mut world = WorldState::new();
+    world.words = vec![
+      Word { id: 1, ..Word::new("tea", 0.2, 0.3) },
+      Word { id: 2, ..Word::new("tea", 0.5, 0.6) },
+      Word { id: 3, ..Word::new("team", 0.8, 0.9) },
+    ];
+    let world = game.apply_key(Event::AddChar('t'), &world);
+    assert_eq!(world.target, Some(3));
+    assert_eq!(world.typed_part(&world.words[2]), Some("t"));
+    assert_eq!(world.typed_part(&world.words[1]), None);
+    let world = game.apply_key(Event::AddChar('e'), &world);
+    assert_eq!(world.target, Some(3));
+    // the target doesn't fit anymore, the closest fitting word takes over
+    let mut moved = world.clone();
+    moved.words[2].word = String::from("text");
+    let moved = game.apply_key(Event::AddChar('a'), &moved);
+    // of two equal words, only the closest one is finished
+    assert_eq!(moved.wordcount, 1);
+    assert_eq!(moved.words.iter().map(|word| word.id).collect::<Vec<u64>>(), vec![1, 3]);
+    assert_eq!(moved.target, None);
+  }
+
+  #[test]
+  fn wrong_keys_are_dropped() {
+    let world = type_text(Matching::default(), &["test"], "texst");
+    assert_eq!(world.wordcount, 1);
+    assert_eq!(world.rejected, 1);
+  }
+
+  #[test]
+  fn strict_keeps_wrong_keys() {
+    let strict = Matching { strict: true, ..Matching::default() };
+    let world = type_text(strict, &["test"], "texst");
+    assert_eq!(world.buffer, "texst");
+    assert!(!world.is_buffer_valid());
+    assert_eq!(world.rejected, 3);
+    assert_eq!(type_text(strict, &["test"], "tex<st").wordcount, 1);
+  }
+
+  #[test]
+  fn case_and_accents_can_be_ignored() {
+    assert_eq!(type_text(Matching::default(), &["Café"], "cafe").wordcount, 0);
+    let ignore_case = Matching { ignore_case: true, ..Matching::default() };
+    assert_eq!(type_text(ignore_case, &["Café"], "cafÉ").wordcount, 1);
+    let ignore_accents = Matching { ignore_accents: true, ..Matching::default() };
+    assert_eq!(type_text(ignore_accents, &["Café"], "Cafe").wordcount, 1);
+  }
+
+  #[test]
+  fn lock_keeps_the_target() {
+    let lock = Matching { lock: true, ..Matching::default() };
+    // "t" fits both words, "tes" only "test"
+    assert!(!type_text(lock, &["test", "tea"], "t").locked);
+    let world = type_text(lock, &["test", "team", "tea"], "tes");
+    assert_eq!(world.target, Some(1));
+    assert!(world.locked);
+    // the lock only ends with an empty buffer
+    let world = type_text(lock, &["tea", "tent"], "ten<a");
+    assert_eq!(world.target, Some(2));
+    assert!(world.locked);
+    assert_eq!(world.rejected, 1);
+    let world = type_text(lock, &["tea", "tent"], "ten<<<tea");
+    assert_eq!(world.wordcount, 1);
+    assert_eq!(world.target, None);
+  }
+
+  #[test]
+  fn submit_needs_space_or_enter() {
+    let submit = Matching { submit: true, ..Matching::default() };
+    let world = type_text(submit, &["tea", "team"], "tea");
+    assert_eq!(world.wordcount, 0);
+    assert_eq!(world.buffer, "tea");
+    assert_eq!(type_text(submit, &["tea", "team"], "tea ").wordcount, 1);
+    assert_eq!(type_text(submit, &["tea", "team"], "team\n").words[0].word, "tea");
+    // enter without a finished word clears the buffer
+    let world = type_text(submit, &["tea"], "te\n");
+    assert_eq!(world.wordcount, 0);
+    assert_eq!(world.buffer, "");
+  }
+
+  #[test]
+  fn zen_keeps_the_speed() {
+    let mut world = WorldState::new();
+    world.words = vec![Word::new("test", 0.5, 0.0)];
+    let zen = Settings { mode: Mode::Zen, ..Settings::default() };
+    world.level = 11;
+    assert!((game(zen).update_world(1000, &world).words[0].y - 0.11).abs() < 1e-9);
+    assert!((game(Settings::default()).update_world(1000, &world).words[0].y - 0.21).abs() < 1e-9);
+    // zen keeps the speed of the level it starts at
+    let zen = Settings { mode: Mode::Zen, start_level: 10, ..Settings::default() };
+    assert!((game(zen).update_world(1000, &world).words[0].y - 0.2).abs() < 1e-9);
+  }
+
+  /// the words spawned on a fresh screen at a level with the given number of words.
+  fn spawn_level(seed: u64, level: Level) -> Vec<Word> {
+    spawn_level_on(seed, level, (80, 22))
+  }
+
+  /// the words spawned on a fresh screen with the given columns and rows.
+  fn spawn_level_on(seed: u64, level: Level, screen: (u16, u16)) -> Vec<Word> {
+    let settings = Settings {
+      progression: Some(Progression::new(vec![level]).unwrap()),
+      boss_levels: 0,
+      spawning: None,
+      screen,
+      ..Settings::default()
+    };
+    let mut game = Typeattack::new_with_settings(Box::new(Headless::new(vec![])), settings, Some(seed), Box::new(StepClock::new(16)));
+    game.update_world(16, &WorldState::new()).words
+  }
+
+  #[test]
+  fn crowded_levels_dont_overlap() {
+    for seed in 1..20 {
+      let words = spawn_level(seed, Level { words: 6, max_length: Some(7), ..Level::default() });
+      assert_eq!(words.len(), 6);
+      let mut spans: Vec<(f64, f64)> = words.iter()
+          .map(|word| placement::span(word.x, text::block_size(&word.word, 80, 22).0))
+          .collect();
+      spans.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+      assert!(spans.windows(2).all(|pair| pair[0].1 < pair[1].0), "overlap with seed {}: {:?}", seed, words);
+    }
+  }
+
+  #[test]
+  fn words_are_placed_on_the_screen_of_the_settings() {
+    for seed in 1..20 {
+      let words = spawn_level_on(seed, Level { words: 4, max_length: Some(7), ..Level::default() }, (40, 22));
+      let mut spans: Vec<(f64, f64)> = words.iter()
+          .map(|word| placement::span(word.x, text::block_size(&word.word, 40, 22).0))
+          .collect();
+      spans.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+      assert!(spans.windows(2).all(|pair| pair[0].1 < pair[1].0), "overlap with seed {}: {:?}", seed, words);
+    }
+  }
+
+  #[test]
+  fn full_levels_spawn_all_words() {
+    let words = spawn_level(1, Level { words: 12, min_length: 10, ..Level::default() });
+    assert_eq!(words.len(), 12);
+    assert!(words.iter().all(|word| (0.0..=1.0).contains(&word.x)));
+  }
+
+  #[test]
+  fn words_come_one_after_another() {
+    let spawning = Spawning { interval: 1000, jitter: 0.0, burst: 0.0, speed_spread: 0.0, wave_words: 0, ..Spawning::default() };
+    let settings = Settings {
+      progression: Some(Progression::new(vec![Level { words: 5, ..Level::default() }]).unwrap()),
+      boss_levels: 0,
+      power_ups: 0.0,
+      spawning: Some(spawning),
+      ..Settings::default()
+    };
+    let mut game = game(settings);
+    let mut world = WorldState::new();
+    let mut counts = vec![];
+    for _ in 0..30 {
+      world = game.update_world(100, &world);
+      counts.push(world.words.len());
+    }
+    assert_eq!(&counts[..11], &[1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 2]);
+    assert_eq!(counts[29], 3);
+    let spawned: Vec<u128> = world.words.iter().map(|word| word.spawned).collect();
+    assert_eq!(spawned, vec![100, 1100, 2100]);
+  }
+
+  #[test]
+  fn words_fall_at_their_own_speed() {
+    let settings = Settings { spawning: Some(Spawning { speed_spread: 0.5, ..Spawning::default() }), ..Settings::default() };
+    let mut game = game(settings);
+    let speeds: Vec<f64> = (0..20).map(|_| game.spawn_word(1, &Difficulty::default(), 0, &[]).unwrap().speed).collect();
+    assert!(speeds.iter().all(|speed| (0.5..=1.5).contains(speed)));
+    assert!(speeds.iter().any(|speed| *speed != speeds[0]));
+    let mut world = WorldState::new();
+    world.words = vec![
+      Word { id: 1, ..Word::new("slow", 0.2, 0.0) },
+      Word { id: 2, speed: 2.0, ..Word::new("fast", 0.8, 0.0) },
+    ];
+    let world = game.update_world(100, &world);
+    assert!((world.words[1].y - 2.0 * world.words[0].y).abs() < 1e-9);
+  }
+
+  #[test]
+  fn words_on_screen_can_be_limited() {
+    let settings = Settings { start_level: 20, max_words: Some(2), ..Settings::default() };
+    let engine = Headless::new(vec![Event::AddChar('s')]);
+    Typeattack::new_with_settings(Box::new(engine.clone()), settings, Some(1), Box::new(StepClock::new(16))).start();
+    let states = engine.world_states();
+    assert!(states.iter().all(|state| state.words.len() <= 2));
+    assert!(states.iter().any(|state| state.words.len() == 2));
+  }
+
+  #[test]
+  fn invalid_settings_dont_start() {
+    let engine = Headless::new(vec![Event::AddChar('s')]);
+    let settings = Settings { level_up_words: 0, ..Settings::default() };
+    Typeattack::new_with_settings(Box::new(engine.clone()), settings, Some(1), Box::new(StepClock::new(16))).start();
+    assert!(engine.frames().is_empty());
+  }
+
+  #[test]
+  fn game_ends_after_all_lives_are_lost() {
+    let result = play_with(Settings { lives: 1, ..Settings::default() });
+    assert_eq!(result.fails, 1);
+  }
+
+  #[test]
+  fn game_ends_after_duration() {
+    let result = play_with(Settings { duration: Some(1000), ..Settings::default() });
+    assert_eq!(result.time, 1008);
+    assert_eq!(result.remaining_time(), Some(0));
+    assert_eq!(result.fails, 0);
+  }
+
+  fn power_up_word(value: &str, y: f64, power_up: PowerUp) -> Word {
+    Word { power_up: Some(power_up), ..Word::new(value, 0.5, y) }
+  }
+
+  #[test]
+  fn spawned_words_can_be_power_ups() {
+    let spawn = |power_ups: f64| {
+      let settings = Settings { power_ups, ..Settings::default() };
+      let mut game = game(settings);
+      (0..50).map(|_| game.spawn_word(1, &Difficulty::default(), 0, &[]).unwrap().power_up).collect::<Vec<_>>()
+    };
+    assert!(spawn(1.0).iter().all(Option::is_some));
+    assert!(spawn(0.0).iter().all(Option::is_none));
+  }
+
+  #[test]
+  fn freeze_and_slow_change_the_speed() {
+    let mut game = game(Settings::default());
+    let mut world = WorldState::new();
+    world.words = vec![Word::new("test", 0.5, 0.0)];
+    let normal = game.update_world(1000, &world).words[0].y;
+    world.effects = vec![Effect { power_up: PowerUp::Slow, until: 2000 }];
+    let slow = game.update_world(1000, &world).words[0].y;
+    assert!((slow - normal / 2.0).abs() < 1e-9);
+    world.effects = vec![Effect { power_up: PowerUp::Freeze, until: 2000 }];
+    let frozen = game.update_world(1000, &world);
+    assert_eq!(frozen.words[0].y, 0.0);
+    // the effect is gone once its time is up
+    let thawed = game.update_world(1000, &frozen);
+    assert!(thawed.effects.is_empty());
+    assert_eq!(thawed.words[0].y, 0.0);
+    assert!((game.update_world(1000, &thawed).words[0].y - normal).abs() < 1e-9);
+  }
+
+  #[test]
+  fn typing_a_power_up_activates_it() {
+    let mut game = game(Settings::default());
+    let mut world = WorldState::new();
+    world.time = 500;
+    world.words = vec![power_up_word("ab", 0.5, PowerUp::Clear), Word { id: 1, ..Word::new("cd", 0.5, 0.0) }];
+    world.buffer = String::from("a");
+    let cleared = game.apply_key(Event::AddChar('b'), &world);
+    assert!(cleared.words.is_empty());
+    assert_eq!(cleared.wordcount, 1);
+    world.words = vec![power_up_word("ab", 0.5, PowerUp::Life)];
+    let life = game.apply_key(Event::AddChar('b'), &world);
+    assert_eq!(life.lives, world.lives + 1);
+    world.words = vec![power_up_word("ab", 0.5, PowerUp::Freeze)];
+    let frozen = game.apply_key(Event::AddChar('b'), &world);
+    assert_eq!(frozen.effects, vec![Effect { power_up: PowerUp::Freeze, until: 3500 }]);
+  }
+
+  #[test]
+  fn double_score_doubles_the_points() {
+    let mut game = game(Settings::default());
+    let mut world = WorldState::new();
+    world.words = vec![power_up_word("test", 0.5, PowerUp::DoubleScore), Word { id: 1, ..Word::new("tea", 0.5, 0.5) }];
+    world.buffer = String::from("tes");
+    let doubled = game.apply_key(Event::AddChar('t'), &world);
+    // the power-up word itself still counts once
+    assert_eq!(doubled.score, 60);
+    let typed = game.apply_key(Event::AddChar('t'), &doubled);
+    let typed = game.apply_key(Event::AddChar('e'), &typed);
+    let typed = game.apply_key(Event::AddChar('a'), &typed);
+    assert_eq!(typed.score, 60 + 2 * 45);
+  }
+
+  #[test]
+  fn bosses_come_every_few_levels() {
+    let settings = Settings { boss_levels: 2, power_ups: 0.0, ..Settings::default() };
+    let mut game = game(settings);
+    let mut world = WorldState::new();
+    world.wordcount = 10;
+    let level_2 = game.update_world(16, &world);
+    let bosses: Vec<&Word> = level_2.words.iter().filter(|word| word.boss).collect();
+    assert_eq!(bosses.len(), 1);
+    assert_eq!(bosses[0].word.split(' ').count(), 4);
+    // the boss comes on top of the other words and falls slower
+    assert_eq!(level_2.words.len(), 2);
+    let later = game.update_world(1000, &level_2);
+    assert!(later.words[1].y < later.words[0].y);
+    let mut world = later.clone();
+    world.wordcount = 20;
+    assert_eq!(game.update_world(16, &world).words.iter().filter(|word| word.boss).count(), 1);
+    world.wordcount = 30;
+    let level_4 = game.update_world(16, &world);
+    let bosses: Vec<&Word> = level_4.words.iter().filter(|word| word.boss).collect();
+    assert_eq!(bosses.len(), 2);
+    assert_eq!(bosses[1].word.split(' ').count(), 5);
+  }
+
+  #[test]
+  fn old_games_have_no_bosses() {
+    let settings = Settings { boss_levels: 0, ..Settings::default() };
+    let mut game = game(settings);
+    let mut world = WorldState::new();
+    world.wordcount = 100;
+    assert!(!game.update_world(16, &world).words.iter().any(|word| word.boss));
+  }
+
+  #[test]
+  fn bosses_need_the_whole_phrase() {
+    let mut game = game(Settings::default());
+    let mut world = WorldState::new();
+    world.words = vec![
+      Word { id: 1, boss: true, ..Word::new("the end", 0.5, 0.5) },
+      Word { id: 2, ..Word::new("the", 0.5, 0.1) },
+    ];
+    for c in "the e".chars() {
+      world = game.apply_key(Event::AddChar(c), &world);
+    }
+    assert_eq!(world.words.len(), 2);
+    assert_eq!(world.buffer, "the e");
+    for c in "nd".chars() {
+      world = game.apply_key(Event::AddChar(c), &world);
+    }
+    assert_eq!(world.words.len(), 1);
+    assert_eq!(world.score, score::BOSS_FACTOR * score::word_points("the end", 0.5));
+  }
+
+  #[test]
+  fn space_continues_a_boss_when_submitting() {
+    let matching = Matching { submit: true, ..Matching::default() };
+    let mut game = game(Settings { matching, ..Settings::default() });
+    let mut world = WorldState::new();
+    world.matching = matching;
+    world.words = vec![
+      Word { id: 1, boss: true, ..Word::new("the end", 0.5, 0.5) },
+      Word { id: 2, ..Word::new("the", 0.5, 0.1) },
+    ];
+    for c in "the end ".chars() {
+      world = game.apply_key(Event::AddChar(c), &world);
+    }
+    assert_eq!(world.words.len(), 1);
+    assert_eq!(world.words[0].word, "the");
+  }
+
+  /// types every word of the text as soon as it appears, stopping after the given number of words.
+  fn read(settings: Settings, bookmarks: Bookmarks, words: usize) -> (Typeattack, Headless) {
+    let mut events = vec![Event::AddChar('s')];
+    for word in settings.words.words.iter().skip(settings.position).take(words) {
+      events.extend(word.chars().map(Event::AddChar));
+    }
+    events.push(Event::Stop);
+    let engine = Headless::new(events);
+    let mut game = Typeattack::new_with_settings(Box::new(engine.clone()), settings, Some(1), Box::new(StepClock::new(16)));
+    game.use_bookmarks(bookmarks);
+    game.start();
+    (game, engine)
+  }
+
+  fn text_settings() -> Settings {
+    let words = WordList::from_text("text", "It was a dark and stormy night; the rain fell in torrents.").unwrap();
+    Settings { mode: Mode::Text, words, power_ups: 0.0, spawning: None, ..Settings::default() }
+  }
+
+  #[test]
+  fn text_comes_in_order() {
+    let (_, engine) = read(text_settings(), Bookmarks::new(), 20);
+    let result = engine.world_states().pop().unwrap();
+    let completed: Vec<String> = result.completed.iter().map(|word| word.word.clone()).collect();
+    assert_eq!(completed, text_settings().words.words);
+    let reading = result.reading.unwrap();
+    assert!(reading.is_finished());
+    assert_eq!((reading.page(), reading.pages()), (1, 1));
+  }
+
+  #[test]
+  fn text_has_no_power_ups() {
+    let (_, engine) = read(Settings { power_ups: 1.0, ..text_settings() }, Bookmarks::new(), 20);
+    let states = engine.world_states();
+    assert!(states.iter().all(|state| state.words.iter().all(|word| word.power_up.is_none())));
+    let completed: Vec<String> = states.last().unwrap().completed.iter().map(|word| word.word.clone()).collect();
+    assert_eq!(completed, text_settings().words.words);
+  }
+
+  #[test]
+  fn text_continues_where_it_stopped() {
+    let (game, _) = read(text_settings(), Bookmarks::new(), 3);
+    // the fourth word was on screen when the game stopped
+    assert_eq!(game.settings.position, 3);
+    assert_eq!(game.bookmarks.get(&text_settings().words), 3);
+    let (_, engine) = read(Settings { position: 3, ..text_settings() }, Bookmarks::new(), 1);
+    let result = engine.world_states().pop().unwrap();
+    assert_eq!(result.completed[0].word, "dark");
+    // a finished text starts over
+    let (game, _) = read(text_settings(), Bookmarks::new(), 20);
+    assert_eq!(game.settings.position, 0);
+  }
+
+  #[test]
+  fn selection_avoids_repeats_and_prefixes() {
+    let words = WordList::parse("fruits", "apple\navocado\nbanana\ncherry", WordFormat::Lines).unwrap();
+    let selection = Selection { frequency: 0.0, recent: 2, prefix: 1 };
+    let settings = Settings { words, selection, power_ups: 0.0, ..Settings::default() };
+    let mut game = game(settings);
+    for _ in 0..20 {
+      let mut on_screen = vec![];
+      for _ in 0..3 {
+        let word = game.spawn_word(1, &Difficulty::default(), 0, &on_screen).unwrap();
+        assert!(!on_screen.iter().any(|other: &Word| other.word.starts_with(&word.word[..1])));
+        on_screen.push(word);
+      }
+    }
+    let picked: Vec<String> = (0..20).map(|_| game.spawn_word(1, &Difficulty::default(), 0, &[]).unwrap().word).collect();
+    assert!(picked.windows(3).all(|words| words[0] != words[1] && words[0] != words[2] && words[1] != words[2]));
   }
 }
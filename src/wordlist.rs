@@ -0,0 +1,294 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::source;
+use crate::text::normalize;
+use crate::words::PACKS;
+
+/// How the words are stored in a file.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WordFormat {
+  /// any text, the words are separated by whitespace. Repeated words are more used.
+  Text,
+  /// one entry per line, empty lines are ignored.
+  Lines,
+  /// a json array of strings, or of objects with a word and its rank.
+  Json,
+}
+
+impl WordFormat {
+  /// `.json` files are json, `.txt` files are text, everything else has one word per line.
+  pub fn from_path(path: &Path) -> Self {
+    match path.extension().and_then(|extension| extension.to_str()) {
+      Some("json") => WordFormat::Json,
+      Some("txt") => WordFormat::Text,
+      _ => WordFormat::Lines
+    }
+  }
+}
+
+/// The words the game picks from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WordList {
+  pub name: String,
+  pub words: Vec<String>,
+  // how used a word is, 1 for the most used one; words without a rank are used less than all others
+  #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+  pub ranks: BTreeMap<String, u32>,
+}
+
+/// An entry of a json word list.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum JsonEntry {
+  Word(String),
+  Ranked { word: String, rank: u32 },
+}
+
+impl WordList {
+  /// the 1000 most used english words.
+  pub fn builtin() -> Self {
+    let (name, _, _) = PACKS[0];
+    WordList::pack(name).unwrap()
+  }
+
+  /// one of the bundled word packs, by its name.
+  pub fn pack(name: &str) -> Option<Self> {
+    PACKS.iter()
+        .find(|(pack, _, _)| *pack == name)
+        .map(|(pack, words, most_used)| {
+          let words: Vec<String> = words.iter().map(|word| normalize(word)).collect();
+          let ranks = most_used.iter()
+              .map(|word| normalize(word))
+              .zip(1..)
+              .filter(|(word, _)| words.contains(word))
+              .collect();
+          WordList {
+            name: String::from(*pack),
+            words,
+            ranks,
+          }
+        })
+  }
+
+  /// the names of all bundled word packs.
+  pub fn packs() -> Vec<&'static str> {
+    PACKS.iter().map(|(name, _, _)| *name).collect()
+  }
+
+  /// A bundled word pack if there is one with the given name, the identifiers and symbols
+  /// of the sources in a directory, otherwise the word list in the file.
+  pub fn find(name: &str) -> Result<Self, String> {
+    match WordList::pack(name) {
+      Some(pack) => Ok(pack),
+      None if Path::new(name).is_dir() => WordList::from_source(Path::new(name)),
+      None => WordList::load(Path::new(name))
+    }
+  }
+
+  /// Collects the identifiers and symbols of all source files within the directory, the most used ones first.
+  pub fn from_source(dir: &Path) -> Result<Self, String> {
+    let words = source::words(dir)?;
+    if words.is_empty() {
+      return Err(format!("There are no source files with words in {}", dir.display()));
+    }
+    let name = dir.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+    let words: Vec<String> = words.iter().map(|word| normalize(word)).collect();
+    // the words come most used first
+    let ranks = words.iter().cloned().zip(1..).collect();
+    Ok(WordList {
+      name,
+      words,
+      ranks,
+    })
+  }
+
+  /// All words of a text in their order, with their punctuation and capitalization.
+  pub fn from_text(name: &str, content: &str) -> Result<Self, String> {
+    let words: Vec<String> = normalize(content).split_whitespace().map(String::from).collect();
+    if words.is_empty() {
+      return Err(format!("There are no words in the text {}", name));
+    }
+    Ok(WordList {
+      name: String::from(name),
+      words,
+      ranks: BTreeMap::new(),
+    })
+  }
+
+  /// Loads a word list from a file, in the format [WordFormat::from_path] detects.
+  pub fn load(path: &Path) -> Result<Self, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|error| format!("Could not read word list {}: {}", path.display(), error))?;
+    let name = path.file_stem().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+    WordList::parse(&name, &content, WordFormat::from_path(path))
+        .map_err(|error| format!("Invalid word list {}: {}", path.display(), error))
+  }
+
+  /// Parses and validates a word list. A list needs at least one word,
+  /// and none of the words may be duplicated or contain non-printable characters.
+  pub fn parse(name: &str, content: &str, format: WordFormat) -> Result<Self, String> {
+    let content = normalize(content);
+    let mut ranks = BTreeMap::new();
+    let words: Vec<String> = match format {
+      WordFormat::Text => {
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        let mut words = Vec::new();
+        for word in content.split_whitespace() {
+          let count = counts.entry(word).or_default();
+          if *count == 0 {
+            words.push(String::from(word));
+          }
+          *count += 1;
+        }
+        // the more often a word is in the text, the more used it is
+        let mut used = words.clone();
+        used.sort_by_key(|word| std::cmp::Reverse(counts[word.as_str()]));
+        ranks = used.into_iter().zip(1..).collect();
+        words
+      }
+      WordFormat::Lines => content.lines()
+          .map(str::trim)
+          .filter(|line| !line.is_empty())
+          .map(String::from)
+          .collect(),
+      WordFormat::Json => {
+        let entries: Vec<JsonEntry> = serde_json::from_str(&content)
+            .map_err(|error| format!("not a json array of strings or of words with ranks: {}", error))?;
+        entries.into_iter().map(|entry| match entry {
+          JsonEntry::Word(word) => word,
+          JsonEntry::Ranked { word, rank } => {
+            ranks.insert(word.clone(), rank);
+            word
+          }
+        }).collect()
+      }
+    };
+    if words.is_empty() {
+      return Err(String::from("there are no words in it"));
+    }
+    let mut seen = HashSet::new();
+    for word in &words {
+      if word.trim().is_empty() {
+        return Err(String::from("it contains an empty entry"));
+      }
+      if word.chars().any(char::is_control) {
+        return Err(format!("{:?} contains non-printable characters", word));
+      }
+      if !seen.insert(word) {
+        return Err(format!("{:?} is in there more than once", word));
+      }
+      if ranks.get(word) == Some(&0) {
+        return Err(format!("{:?} has the rank 0, but ranks start at 1", word));
+      }
+    }
+    Ok(WordList {
+      name: String::from(name),
+      words,
+      ranks,
+    })
+  }
+
+  /// how used the word is, 1 for the most used one.
+  pub fn rank(&self, word: &str) -> Option<u32> {
+    self.ranks.get(word).copied()
+  }
+}
+
+impl Default for WordList {
+  fn default() -> Self {
+    Self::builtin()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::path::Path;
+
+  use crate::wordlist::{WordFormat, WordList};
+
+  #[test]
+  fn format_from_path() {
+    assert_eq!(WordFormat::from_path(Path::new("words.json")), WordFormat::Json);
+    assert_eq!(WordFormat::from_path(Path::new("book.txt")), WordFormat::Text);
+    assert_eq!(WordFormat::from_path(Path::new("words")), WordFormat::Lines);
+  }
+
+  #[test]
+  fn parse_text() {
+    let list = WordList::parse("text", "the cat\nand the  dog\n", WordFormat::Text).unwrap();
+    assert_eq!(list.words, vec!["the", "cat", "and", "dog"]);
+    assert_eq!(list.rank("the"), Some(1));
+    assert_eq!(list.rank("cat"), Some(2));
+  }
+
+  #[test]
+  fn parse_lines() {
+    let list = WordList::parse("lines", "cat\n\n  ice cream \n", WordFormat::Lines).unwrap();
+    assert_eq!(list.words, vec!["cat", "ice cream"]);
+  }
+
+  #[test]
+  fn parse_json() {
+    let list = WordList::parse("json", r#"["cat", "dog"]"#, WordFormat::Json).unwrap();
+    assert_eq!(list.words, vec!["cat", "dog"]);
+    let list = WordList::parse("json", r#"[{"word": "cat", "rank": 2}, "dog"]"#, WordFormat::Json).unwrap();
+    assert_eq!(list.words, vec!["cat", "dog"]);
+    assert_eq!((list.rank("cat"), list.rank("dog")), (Some(2), None));
+    assert!(WordList::parse("json", r#"{"words": []}"#, WordFormat::Json).is_err());
+    let error = WordList::parse("json", r#"[{"word": "cat", "rank": 0}]"#, WordFormat::Json).unwrap_err();
+    assert!(error.contains("\"cat\""), "{}", error);
+  }
+
+  #[test]
+  fn invalid_lists() {
+    assert!(WordList::parse("empty", " \n\n", WordFormat::Lines).is_err());
+    assert!(WordList::parse("empty", "[]", WordFormat::Json).is_err());
+    assert!(WordList::parse("duplicate", "cat\ndog\ncat", WordFormat::Lines).is_err());
+    assert!(WordList::parse("blank", r#"["cat", " "]"#, WordFormat::Json).is_err());
+    assert!(WordList::parse("control", r#"["cat", "d\u0007g"]"#, WordFormat::Json).is_err());
+  }
+
+  #[test]
+  fn packs_are_valid() {
+    for name in WordList::packs() {
+      let pack = WordList::pack(name).unwrap();
+      let content = serde_json::to_string(&pack.words).unwrap();
+      assert_eq!(WordList::parse(name, &content, WordFormat::Json).unwrap().words, pack.words);
+      assert!(pack.ranks.keys().all(|word| pack.words.contains(word)));
+    }
+    assert_eq!(WordList::builtin().name, "english");
+    assert_eq!(WordList::builtin().rank("the"), Some(1));
+    assert_eq!(WordList::builtin().rank("responsibility"), None);
+    assert!(WordList::pack("klingon").is_none());
+  }
+
+  #[test]
+  fn source_directories() {
+    let dir = std::env::temp_dir().join(format!("typeattack-wordlist-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    assert!(WordList::find(dir.to_str().unwrap()).is_err());
+    std::fs::write(dir.join("main.go"), "func main() {}").unwrap();
+    let list = WordList::find(dir.to_str().unwrap());
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(list.unwrap().words, vec!["()", "func", "main", "{}"]);
+  }
+
+  #[test]
+  fn text_keeps_everything() {
+    let text = WordList::from_text("text", "The cat, the  dog.\nThe end.").unwrap();
+    assert_eq!(text.words, vec!["The", "cat,", "the", "dog.", "The", "end."]);
+    assert!(WordList::from_text("text", " \n").is_err());
+  }
+
+  #[test]
+  fn words_are_normalized() {
+    let list = WordList::parse("lines", "cafe\u{301}\ncafé", WordFormat::Lines);
+    assert!(list.is_err());
+    let list = WordList::parse("lines", "cafe\u{301}", WordFormat::Lines).unwrap();
+    assert_eq!(list.words, vec!["café"]);
+  }
+}
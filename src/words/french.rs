@@ -0,0 +1,221 @@
+pub const WORDS: &[&str] = &[
+  "le",
+  "la",
+  "de",
+  "un",
+  "et",
+  "je",
+  "tu",
+  "il",
+  "où",
+  "là",
+  "les",
+  "des",
+  "une",
+  "ils",
+  "que",
+  "qui",
+  "pas",
+  "sur",
+  "été",
+  "thé",
+  "âge",
+  "vie",
+  "eau",
+  "bon",
+  "ici",
+  "oui",
+  "non",
+  "mer",
+  "être",
+  "elle",
+  "nous",
+  "vous",
+  "dans",
+  "pour",
+  "avec",
+  "plus",
+  "dire",
+  "voir",
+  "très",
+  "bien",
+  "déjà",
+  "père",
+  "mère",
+  "sœur",
+  "café",
+  "même",
+  "cœur",
+  "noël",
+  "jour",
+  "nuit",
+  "main",
+  "pays",
+  "pain",
+  "lait",
+  "ciel",
+  "lune",
+  "lent",
+  "vrai",
+  "faux",
+  "beau",
+  "hier",
+  "prix",
+  "lire",
+  "fête",
+  "égal",
+  "avoir",
+  "elles",
+  "faire",
+  "aller",
+  "venir",
+  "frère",
+  "école",
+  "élève",
+  "forêt",
+  "année",
+  "temps",
+  "homme",
+  "femme",
+  "monde",
+  "ville",
+  "chose",
+  "livre",
+  "train",
+  "avion",
+  "pluie",
+  "neige",
+  "hiver",
+  "chaud",
+  "froid",
+  "clair",
+  "grand",
+  "petit",
+  "vieux",
+  "jeune",
+  "merci",
+  "payer",
+  "finir",
+  "jouer",
+  "voler",
+  "nager",
+  "boire",
+  "pièce",
+  "musée",
+  "dîner",
+  "savoir",
+  "donner",
+  "garçon",
+  "enfant",
+  "maison",
+  "viande",
+  "jardin",
+  "soleil",
+  "étoile",
+  "rapide",
+  "sombre",
+  "facile",
+  "triste",
+  "jamais",
+  "demain",
+  "argent",
+  "vendre",
+  "ouvrir",
+  "fermer",
+  "écrire",
+  "courir",
+  "manger",
+  "dormir",
+  "marché",
+  "pouvoir",
+  "vouloir",
+  "prendre",
+  "trouver",
+  "fenêtre",
+  "château",
+  "hôpital",
+  "travail",
+  "chambre",
+  "cuisine",
+  "voiture",
+  "automne",
+  "heureux",
+  "premier",
+  "dernier",
+  "nouveau",
+  "mauvais",
+  "souvent",
+  "bonjour",
+  "réponse",
+  "exemple",
+  "journal",
+  "acheter",
+  "théâtre",
+  "rivière",
+  "élégant",
+  "étrange",
+  "général",
+  "préféré",
+  "français",
+  "toujours",
+  "question",
+  "histoire",
+  "nouvelle",
+  "conduire",
+  "montagne",
+  "déjeuner",
+  "printemps",
+  "difficile",
+  "commencer",
+  "apprendre",
+  "enseigner",
+  "télévision",
+  "aujourd'hui",
+  "intéressant"
+];
+
+/// the most used words, most used first.
+pub const MOST_USED: &[&str] = &[
+  "de",
+  "la",
+  "le",
+  "et",
+  "les",
+  "des",
+  "en",
+  "un",
+  "du",
+  "une",
+  "que",
+  "est",
+  "pour",
+  "qui",
+  "dans",
+  "a",
+  "par",
+  "plus",
+  "pas",
+  "au",
+  "sur",
+  "ne",
+  "se",
+  "ce",
+  "il",
+  "sont",
+  "avec",
+  "ou",
+  "son",
+  "lui",
+  "nous",
+  "comme",
+  "mais",
+  "on",
+  "tout",
+  "elle",
+  "être",
+  "fait",
+  "je",
+  "aussi",
+  "leur",
+  "bien"
+];
@@ -0,0 +1,260 @@
+pub const WORDS: &[&str] = &[
+  "er",
+  "es",
+  "zu",
+  "so",
+  "an",
+  "um",
+  "ja",
+  "Öl",
+  "der",
+  "die",
+  "das",
+  "und",
+  "ich",
+  "mit",
+  "sie",
+  "auf",
+  "für",
+  "ein",
+  "von",
+  "dem",
+  "den",
+  "wie",
+  "wir",
+  "nur",
+  "aus",
+  "gut",
+  "bei",
+  "neu",
+  "alt",
+  "Tag",
+  "Bär",
+  "Tür",
+  "Fuß",
+  "Zug",
+  "sein",
+  "eine",
+  "aber",
+  "auch",
+  "noch",
+  "wenn",
+  "kann",
+  "dann",
+  "sehr",
+  "über",
+  "nach",
+  "mehr",
+  "groß",
+  "lang",
+  "hoch",
+  "früh",
+  "spät",
+  "hier",
+  "dort",
+  "nein",
+  "Haus",
+  "Jahr",
+  "Zeit",
+  "Mann",
+  "Frau",
+  "Kind",
+  "Welt",
+  "Hand",
+  "Land",
+  "Käse",
+  "müde",
+  "Brot",
+  "Buch",
+  "Mond",
+  "warm",
+  "kalt",
+  "hell",
+  "Geld",
+  "nicht",
+  "haben",
+  "schon",
+  "sagen",
+  "gehen",
+  "sehen",
+  "geben",
+  "klein",
+  "schön",
+  "immer",
+  "heute",
+  "Leben",
+  "Stadt",
+  "Größe",
+  "Apfel",
+  "Äpfel",
+  "Grüße",
+  "Übung",
+  "Mütze",
+  "hören",
+  "Woche",
+  "Monat",
+  "Vater",
+  "Milch",
+  "Küche",
+  "Wagen",
+  "Sonne",
+  "Stern",
+  "Regen",
+  "Frage",
+  "Preis",
+  "enden",
+  "lesen",
+  "essen",
+  "werden",
+  "können",
+  "müssen",
+  "machen",
+  "kommen",
+  "wissen",
+  "wieder",
+  "morgen",
+  "Arbeit",
+  "Schule",
+  "Straße",
+  "Brücke",
+  "Gemüse",
+  "fühlen",
+  "zählen",
+  "Stunde",
+  "Minute",
+  "Freund",
+  "Mutter",
+  "Bruder",
+  "Wasser",
+  "Zimmer",
+  "Garten",
+  "Himmel",
+  "Schnee",
+  "Winter",
+  "Sommer",
+  "Herbst",
+  "dunkel",
+  "falsch",
+  "leicht",
+  "schwer",
+  "kaufen",
+  "öffnen",
+  "lernen",
+  "lehren",
+  "laufen",
+  "fahren",
+  "Mädchen",
+  "träumen",
+  "Familie",
+  "Fleisch",
+  "schnell",
+  "langsam",
+  "richtig",
+  "traurig",
+  "ähnlich",
+  "möglich",
+  "wichtig",
+  "Fahrrad",
+  "Bahnhof",
+  "Zukunft",
+  "Antwort",
+  "Zeitung",
+  "spielen",
+  "fliegen",
+  "trinken",
+  "Freundin",
+  "Flugzeug",
+  "Frühling",
+  "fröhlich",
+  "Geschäft",
+  "Gespräch",
+  "Beispiel",
+  "Rechnung",
+  "bezahlen",
+  "beginnen",
+  "schlafen",
+  "Schwester",
+  "natürlich",
+  "gemütlich",
+  "Erklärung",
+  "Frühstück",
+  "Flughafen",
+  "Schlüssel",
+  "Gegenwart",
+  "Nachricht",
+  "Fernseher",
+  "verkaufen",
+  "schließen",
+  "schreiben",
+  "schwimmen",
+  "Abendessen",
+  "Wörterbuch",
+  "Geschichte",
+  "Mittagessen",
+  "Krankenhaus",
+  "Universität",
+  "Vergangenheit",
+  "Entschuldigung",
+  "Geschwindigkeit"
+];
+
+/// the most used words, most used first.
+pub const MOST_USED: &[&str] = &[
+  "der",
+  "die",
+  "und",
+  "in",
+  "den",
+  "von",
+  "zu",
+  "das",
+  "mit",
+  "sich",
+  "des",
+  "auf",
+  "für",
+  "ist",
+  "im",
+  "dem",
+  "nicht",
+  "ein",
+  "eine",
+  "als",
+  "auch",
+  "es",
+  "an",
+  "werden",
+  "aus",
+  "er",
+  "hat",
+  "dass",
+  "sie",
+  "nach",
+  "wird",
+  "bei",
+  "einer",
+  "um",
+  "am",
+  "sind",
+  "noch",
+  "wie",
+  "einem",
+  "über",
+  "einen",
+  "so",
+  "zum",
+  "war",
+  "haben",
+  "nur",
+  "oder",
+  "aber",
+  "vor",
+  "zur",
+  "bis",
+  "mehr",
+  "durch",
+  "man",
+  "sein",
+  "wurde",
+  "sei"
+];
@@ -0,0 +1,100 @@
+pub const WORDS: &[&str] = &[
+  "break",
+  "case",
+  "chan",
+  "const",
+  "continue",
+  "default",
+  "defer",
+  "else",
+  "fallthrough",
+  "for",
+  "func",
+  "go",
+  "goto",
+  "if",
+  "import",
+  "interface",
+  "map",
+  "package",
+  "range",
+  "return",
+  "select",
+  "struct",
+  "switch",
+  "type",
+  "var",
+  "bool",
+  "byte",
+  "rune",
+  "int",
+  "int64",
+  "uint",
+  "uint8",
+  "float64",
+  "string",
+  "error",
+  "any",
+  "nil",
+  "true",
+  "false",
+  "iota",
+  "make",
+  "new",
+  "len",
+  "cap",
+  "append",
+  "copy",
+  "delete",
+  "panic",
+  "recover",
+  "close",
+  "main",
+  "err",
+  "ctx",
+  "fmt",
+  "fmt.Println",
+  "fmt.Printf",
+  "fmt.Sprintf",
+  "fmt.Errorf",
+  "errors.New",
+  "errors.Is",
+  "strings",
+  "strconv",
+  "strconv.Itoa",
+  "os",
+  "io",
+  "http",
+  "time",
+  "sync",
+  "context",
+  "context.Context",
+  "sync.Mutex",
+  "sync.WaitGroup",
+  "http.Handler",
+  "time.Duration",
+  "[]byte",
+  "[]string",
+  "map[string]int",
+  "interface{}",
+  "struct{}",
+  "chan<-",
+  "<-chan",
+  "*T",
+  "&T",
+  ":=",
+  "<-",
+  "==",
+  "!=",
+  "&&",
+  "||",
+  "<=",
+  ">=",
+  "+=",
+  "++",
+  "--",
+  "{}",
+  "()",
+  "[]",
+  "..."
+];
@@ -0,0 +1,119 @@
+pub const WORDS: &[&str] = &[
+  "async",
+  "await",
+  "break",
+  "case",
+  "catch",
+  "class",
+  "const",
+  "continue",
+  "debugger",
+  "default",
+  "delete",
+  "do",
+  "else",
+  "export",
+  "extends",
+  "finally",
+  "for",
+  "function",
+  "if",
+  "import",
+  "in",
+  "instanceof",
+  "let",
+  "new",
+  "of",
+  "return",
+  "static",
+  "super",
+  "switch",
+  "this",
+  "throw",
+  "try",
+  "typeof",
+  "var",
+  "void",
+  "while",
+  "yield",
+  "null",
+  "undefined",
+  "true",
+  "false",
+  "NaN",
+  "Infinity",
+  "console",
+  "console.log",
+  "console.error",
+  "document",
+  "window",
+  "JSON",
+  "JSON.stringify",
+  "JSON.parse",
+  "Promise",
+  "Promise.all",
+  "Array",
+  "Array.isArray",
+  "Object",
+  "Object.keys",
+  "Object.entries",
+  "Map",
+  "Set",
+  "Math",
+  "Math.max",
+  "Math.floor",
+  "Date",
+  "Error",
+  "String",
+  "Number",
+  "Boolean",
+  "fetch",
+  "then",
+  "map",
+  "filter",
+  "reduce",
+  "forEach",
+  "find",
+  "some",
+  "every",
+  "includes",
+  "indexOf",
+  "push",
+  "pop",
+  "slice",
+  "splice",
+  "concat",
+  "join",
+  "split",
+  "length",
+  "toString",
+  "addEventListener",
+  "querySelector",
+  "getElementById",
+  "setTimeout",
+  "setInterval",
+  "require",
+  "module.exports",
+  "prototype",
+  "constructor",
+  "=>",
+  "===",
+  "!==",
+  "==",
+  "!=",
+  "&&",
+  "||",
+  "??",
+  "?.",
+  "...",
+  "<=",
+  ">=",
+  "+=",
+  "-=",
+  "++",
+  "--",
+  "{}",
+  "()",
+  "[]",
+  "${}"
+];
@@ -0,0 +1,25 @@
+mod english;
+mod french;
+mod german;
+mod go;
+mod javascript;
+mod polish;
+mod python;
+mod rust;
+mod shell;
+mod spanish;
+
+/// The bundled word packs by name, with their most used words in order. The first one is the default.
+pub const PACKS: &[(&str, &[&str], &[&str])] = &[
+  ("english", english::WORDS, english::MOST_USED),
+  ("german", german::WORDS, german::MOST_USED),
+  ("french", french::WORDS, french::MOST_USED),
+  ("spanish", spanish::WORDS, spanish::MOST_USED),
+  ("polish", polish::WORDS, polish::MOST_USED),
+  // keywords, common identifiers and symbols of programming languages
+  ("rust", rust::WORDS, &[]),
+  ("python", python::WORDS, &[]),
+  ("go", go::WORDS, &[]),
+  ("javascript", javascript::WORDS, &[]),
+  ("shell", shell::WORDS, &[]),
+];
@@ -0,0 +1,211 @@
+pub const WORDS: &[&str] = &[
+  "i",
+  "w",
+  "z",
+  "na",
+  "do",
+  "to",
+  "że",
+  "co",
+  "od",
+  "po",
+  "nie",
+  "się",
+  "jak",
+  "ale",
+  "tak",
+  "już",
+  "czy",
+  "być",
+  "móc",
+  "iść",
+  "noc",
+  "rok",
+  "dom",
+  "oko",
+  "gęś",
+  "źle",
+  "mąż",
+  "pić",
+  "zły",
+  "tam",
+  "las",
+  "jest",
+  "jego",
+  "mnie",
+  "może",
+  "mieć",
+  "czas",
+  "kraj",
+  "ręka",
+  "żółw",
+  "łódź",
+  "ślub",
+  "żona",
+  "wieś",
+  "pięć",
+  "jeść",
+  "spać",
+  "woda",
+  "zima",
+  "lato",
+  "duży",
+  "mały",
+  "nowy",
+  "cena",
+  "grać",
+  "brat",
+  "góra",
+  "tylko",
+  "robić",
+  "mówić",
+  "dzień",
+  "życie",
+  "praca",
+  "głowa",
+  "matka",
+  "świat",
+  "ulica",
+  "ciało",
+  "serce",
+  "sześć",
+  "chleb",
+  "mleko",
+  "mięso",
+  "pokój",
+  "ogród",
+  "niebo",
+  "śnieg",
+  "wolno",
+  "zimno",
+  "jasny",
+  "fałsz",
+  "łatwy",
+  "stary",
+  "młody",
+  "dobry",
+  "nigdy",
+  "jutro",
+  "tutaj",
+  "uczyć",
+  "pisać",
+  "latać",
+  "rzeka",
+  "morze",
+  "żółty",
+  "biały",
+  "chcieć",
+  "ludzie",
+  "miasto",
+  "ojciec",
+  "źródło",
+  "szkoła",
+  "pociąg",
+  "słońce",
+  "deszcz",
+  "wiosna",
+  "jesień",
+  "szybko",
+  "ciepło",
+  "ciemny",
+  "prawda",
+  "trudny",
+  "smutny",
+  "piękny",
+  "zawsze",
+  "często",
+  "proszę",
+  "gazeta",
+  "płacić",
+  "czytać",
+  "biegać",
+  "jechać",
+  "pływać",
+  "minuta",
+  "święto",
+  "czarny",
+  "dziecko",
+  "kobieta",
+  "książka",
+  "kuchnia",
+  "samolot",
+  "księżyc",
+  "gwiazda",
+  "ostatni",
+  "dzisiaj",
+  "wczoraj",
+  "pytanie",
+  "kupować",
+  "zamykać",
+  "kończyć",
+  "tydzień",
+  "miesiąc",
+  "godzina",
+  "rodzina",
+  "siostra",
+  "zielony",
+  "wiedzieć",
+  "człowiek",
+  "dziesięć",
+  "samochód",
+  "pierwszy",
+  "dziękuję",
+  "przykład",
+  "historia",
+  "otwierać",
+  "zaczynać",
+  "czerwony",
+  "mężczyzna",
+  "odpowiedź",
+  "wiadomość",
+  "telewizor",
+  "pieniądze",
+  "niebieski",
+  "szczęśliwy",
+  "sprzedawać",
+  "przyjaciel"
+];
+
+/// the most used words, most used first.
+pub const MOST_USED: &[&str] = &[
+  "w",
+  "i",
+  "się",
+  "na",
+  "nie",
+  "z",
+  "do",
+  "to",
+  "że",
+  "a",
+  "o",
+  "jak",
+  "ale",
+  "po",
+  "co",
+  "tak",
+  "za",
+  "od",
+  "jest",
+  "jego",
+  "go",
+  "już",
+  "tylko",
+  "przez",
+  "jednak",
+  "mi",
+  "ja",
+  "może",
+  "tym",
+  "też",
+  "czy",
+  "dla",
+  "ich",
+  "pan",
+  "był",
+  "być",
+  "są",
+  "ten",
+  "który",
+  "bardzo"
+];
@@ -0,0 +1,130 @@
+pub const WORDS: &[&str] = &[
+  "False",
+  "None",
+  "True",
+  "and",
+  "as",
+  "assert",
+  "async",
+  "await",
+  "break",
+  "class",
+  "continue",
+  "def",
+  "del",
+  "elif",
+  "else",
+  "except",
+  "finally",
+  "for",
+  "from",
+  "global",
+  "if",
+  "import",
+  "in",
+  "is",
+  "lambda",
+  "nonlocal",
+  "not",
+  "or",
+  "pass",
+  "raise",
+  "return",
+  "try",
+  "while",
+  "with",
+  "yield",
+  "self",
+  "cls",
+  "print",
+  "len",
+  "range",
+  "enumerate",
+  "zip",
+  "map",
+  "filter",
+  "sorted",
+  "reversed",
+  "sum",
+  "min",
+  "max",
+  "abs",
+  "list",
+  "dict",
+  "set",
+  "tuple",
+  "str",
+  "int",
+  "float",
+  "bool",
+  "bytes",
+  "isinstance",
+  "open",
+  "super",
+  "type",
+  "object",
+  "input",
+  "iter",
+  "next",
+  "__init__",
+  "__name__",
+  "__main__",
+  "__str__",
+  "__repr__",
+  "__len__",
+  "append",
+  "extend",
+  "insert",
+  "remove",
+  "pop",
+  "items",
+  "keys",
+  "values",
+  "get",
+  "update",
+  "join",
+  "split",
+  "strip",
+  "replace",
+  "format",
+  "startswith",
+  "endswith",
+  "lower",
+  "upper",
+  "Exception",
+  "ValueError",
+  "TypeError",
+  "KeyError",
+  "IndexError",
+  "os",
+  "sys",
+  "re",
+  "json",
+  "math",
+  "pathlib",
+  "typing",
+  "Optional",
+  "List",
+  "Dict",
+  "@property",
+  "@staticmethod",
+  "@classmethod",
+  "@dataclass",
+  "**kwargs",
+  "*args",
+  "f\"\"",
+  "->",
+  "==",
+  "!=",
+  "<=",
+  ">=",
+  ":=",
+  "+=",
+  "-=",
+  "**",
+  "//",
+  "[]",
+  "{}",
+  "()",
+  ":"
+];
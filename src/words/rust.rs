@@ -0,0 +1,156 @@
+pub const WORDS: &[&str] = &[
+  "as",
+  "async",
+  "await",
+  "break",
+  "const",
+  "continue",
+  "crate",
+  "dyn",
+  "else",
+  "enum",
+  "extern",
+  "false",
+  "fn",
+  "for",
+  "if",
+  "impl",
+  "in",
+  "let",
+  "loop",
+  "match",
+  "mod",
+  "move",
+  "mut",
+  "pub",
+  "ref",
+  "return",
+  "self",
+  "Self",
+  "static",
+  "struct",
+  "super",
+  "trait",
+  "true",
+  "type",
+  "unsafe",
+  "use",
+  "where",
+  "while",
+  "i8",
+  "i32",
+  "i64",
+  "u8",
+  "u32",
+  "u64",
+  "usize",
+  "f32",
+  "f64",
+  "bool",
+  "char",
+  "str",
+  "&str",
+  "String",
+  "Vec",
+  "Vec<T>",
+  "Option",
+  "Some",
+  "None",
+  "Result",
+  "Ok",
+  "Err",
+  "Box",
+  "Rc",
+  "Arc",
+  "RefCell",
+  "Mutex",
+  "HashMap",
+  "HashSet",
+  "BTreeMap",
+  "Iterator",
+  "Clone",
+  "Copy",
+  "Debug",
+  "Default",
+  "PartialEq",
+  "Eq",
+  "Hash",
+  "Ord",
+  "Display",
+  "From",
+  "Into",
+  "AsRef",
+  "Send",
+  "Sync",
+  "Sized",
+  "Drop",
+  "Fn",
+  "FnMut",
+  "FnOnce",
+  "println!",
+  "format!",
+  "vec!",
+  "assert!",
+  "assert_eq!",
+  "panic!",
+  "todo!",
+  "matches!",
+  "write!",
+  "unwrap",
+  "expect",
+  "map",
+  "map_err",
+  "and_then",
+  "unwrap_or",
+  "ok_or",
+  "iter",
+  "iter_mut",
+  "into_iter",
+  "collect",
+  "filter",
+  "filter_map",
+  "len",
+  "push",
+  "pop",
+  "insert",
+  "remove",
+  "get",
+  "clone",
+  "to_string",
+  "as_str",
+  "is_empty",
+  "contains",
+  "new",
+  "main",
+  "std",
+  "std::io",
+  "std::fs",
+  "Self::new",
+  "#[derive]",
+  "#[test]",
+  "#[cfg(test)]",
+  "&self",
+  "&mut",
+  "'a",
+  "'static",
+  "->",
+  "=>",
+  "::",
+  "&&",
+  "||",
+  "==",
+  "!=",
+  "<=",
+  ">=",
+  "+=",
+  "-=",
+  "{}",
+  "()",
+  "[]",
+  "<>",
+  "..",
+  "..=",
+  "?",
+  "|x|",
+  ";"
+];
@@ -0,0 +1,121 @@
+pub const WORDS: &[&str] = &[
+  "if",
+  "then",
+  "else",
+  "elif",
+  "fi",
+  "case",
+  "esac",
+  "for",
+  "while",
+  "until",
+  "do",
+  "done",
+  "in",
+  "function",
+  "return",
+  "exit",
+  "break",
+  "continue",
+  "local",
+  "export",
+  "readonly",
+  "declare",
+  "source",
+  "alias",
+  "echo",
+  "printf",
+  "read",
+  "cd",
+  "pwd",
+  "ls",
+  "cp",
+  "mv",
+  "rm",
+  "mkdir",
+  "rmdir",
+  "touch",
+  "cat",
+  "less",
+  "grep",
+  "sed",
+  "awk",
+  "find",
+  "xargs",
+  "sort",
+  "uniq",
+  "head",
+  "tail",
+  "wc",
+  "cut",
+  "tr",
+  "tee",
+  "diff",
+  "chmod",
+  "chown",
+  "sudo",
+  "kill",
+  "ps",
+  "top",
+  "curl",
+  "wget",
+  "tar",
+  "gzip",
+  "git",
+  "ssh",
+  "scp",
+  "test",
+  "true",
+  "false",
+  "set",
+  "unset",
+  "shift",
+  "trap",
+  "eval",
+  "exec",
+  "wait",
+  "sleep",
+  "date",
+  "env",
+  "which",
+  "basename",
+  "dirname",
+  "$?",
+  "$@",
+  "$#",
+  "$0",
+  "$1",
+  "$$",
+  "$HOME",
+  "$PATH",
+  "$PWD",
+  "${}",
+  "$()",
+  "&&",
+  "||",
+  "|",
+  ">",
+  ">>",
+  "<",
+  "<<",
+  "2>&1",
+  "&>",
+  ";;",
+  "[[",
+  "]]",
+  "-eq",
+  "-ne",
+  "-lt",
+  "-gt",
+  "-le",
+  "-ge",
+  "-z",
+  "-n",
+  "-f",
+  "-d",
+  "-e",
+  "/dev/null",
+  "#!/bin/sh",
+  "#!/bin/bash",
+  "~"
+];
@@ -0,0 +1,257 @@
+pub const WORDS: &[&str] = &[
+  "y",
+  "o",
+  "el",
+  "la",
+  "de",
+  "en",
+  "un",
+  "se",
+  "no",
+  "su",
+  "le",
+  "lo",
+  "ir",
+  "si",
+  "me",
+  "ya",
+  "él",
+  "mi",
+  "yo",
+  "ni",
+  "sí",
+  "que",
+  "una",
+  "ser",
+  "por",
+  "con",
+  "más",
+  "ese",
+  "ver",
+  "dar",
+  "muy",
+  "sin",
+  "vez",
+  "qué",
+  "año",
+  "dos",
+  "así",
+  "eso",
+  "nos",
+  "día",
+  "uno",
+  "tan",
+  "mes",
+  "pan",
+  "sol",
+  "para",
+  "como",
+  "todo",
+  "pero",
+  "este",
+  "otro",
+  "ella",
+  "bien",
+  "poco",
+  "cosa",
+  "vida",
+  "nada",
+  "cada",
+  "algo",
+  "solo",
+  "niño",
+  "niña",
+  "café",
+  "frío",
+  "hora",
+  "agua",
+  "tren",
+  "luna",
+  "leer",
+  "haber",
+  "estar",
+  "tener",
+  "hacer",
+  "poder",
+  "decir",
+  "mucho",
+  "saber",
+  "sobre",
+  "mismo",
+  "hasta",
+  "entre",
+  "desde",
+  "pasar",
+  "deber",
+  "poner",
+  "tanto",
+  "donde",
+  "ahora",
+  "parte",
+  "creer",
+  "dejar",
+  "menos",
+  "nuevo",
+  "árbol",
+  "jamón",
+  "señor",
+  "lápiz",
+  "fácil",
+  "lento",
+  "noche",
+  "amigo",
+  "padre",
+  "madre",
+  "leche",
+  "carne",
+  "libro",
+  "coche",
+  "avión",
+  "cielo",
+  "nieve",
+  "otoño",
+  "pagar",
+  "abrir",
+  "jugar",
+  "volar",
+  "nadar",
+  "comer",
+  "beber",
+  "porque",
+  "cuando",
+  "alguno",
+  "querer",
+  "grande",
+  "llegar",
+  "tiempo",
+  "hombre",
+  "quedar",
+  "hablar",
+  "llevar",
+  "seguir",
+  "mañana",
+  "señora",
+  "España",
+  "música",
+  "número",
+  "rápido",
+  "semana",
+  "minuto",
+  "cocina",
+  "jardín",
+  "lluvia",
+  "verano",
+  "dinero",
+  "precio",
+  "vender",
+  "cerrar",
+  "correr",
+  "dormir",
+  "también",
+  "primero",
+  "parecer",
+  "nuestro",
+  "después",
+  "siempre",
+  "corazón",
+  "canción",
+  "pequeño",
+  "español",
+  "difícil",
+  "familia",
+  "hermano",
+  "hermana",
+  "ejemplo",
+  "comprar",
+  "empezar",
+  "enseñar",
+  "entonces",
+  "teléfono",
+  "caliente",
+  "estrella",
+  "invierno",
+  "pregunta",
+  "historia",
+  "terminar",
+  "aprender",
+  "escribir",
+  "conducir",
+  "encontrar",
+  "primavera",
+  "respuesta",
+  "periódico",
+  "habitación"
+];
+
+/// the most used words, most used first.
+pub const MOST_USED: &[&str] = &[
+  "de",
+  "la",
+  "que",
+  "el",
+  "en",
+  "y",
+  "a",
+  "los",
+  "se",
+  "del",
+  "las",
+  "un",
+  "por",
+  "con",
+  "no",
+  "una",
+  "su",
+  "para",
+  "es",
+  "al",
+  "lo",
+  "como",
+  "más",
+  "pero",
+  "sus",
+  "le",
+  "ya",
+  "o",
+  "este",
+  "sí",
+  "porque",
+  "esta",
+  "entre",
+  "cuando",
+  "muy",
+  "sin",
+  "sobre",
+  "también",
+  "me",
+  "hasta",
+  "hay",
+  "donde",
+  "quien",
+  "desde",
+  "todo",
+  "nos",
+  "durante",
+  "todos",
+  "uno",
+  "les",
+  "ni",
+  "contra",
+  "otros",
+  "ese",
+  "eso",
+  "ante",
+  "ellos",
+  "e",
+  "esto",
+  "mí",
+  "antes",
+  "algunos",
+  "qué",
+  "unos",
+  "yo",
+  "otro",
+  "otras",
+  "otra",
+  "él"
+];